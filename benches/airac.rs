@@ -0,0 +1,32 @@
+use airac::AIRAC;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn from_ymd(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_ymd");
+    for year in [1900, 2020, 2100, 3000] {
+        group.bench_with_input(BenchmarkId::from_parameter(year), &year, |b, &year| {
+            b.iter(|| AIRAC::from_ymd(black_box(year), 6, 15))
+        });
+    }
+    group.finish();
+}
+
+fn display(c: &mut Criterion) {
+    let mut group = c.benchmark_group("display");
+    for year in [1900, 2020, 2100, 3000] {
+        let airac = AIRAC::from_ymd(year, 12, 31);
+        group.bench_with_input(BenchmarkId::from_parameter(year), &airac, |b, airac| {
+            b.iter(|| black_box(airac).to_string())
+        });
+    }
+    group.finish();
+}
+
+fn from_ident(c: &mut Criterion) {
+    c.bench_function("from_ident", |b| {
+        b.iter(|| AIRAC::from_ident(black_box("2213")))
+    });
+}
+
+criterion_group!(benches, from_ymd, display, from_ident);
+criterion_main!(benches);
@@ -0,0 +1,307 @@
+//! Effective dates in AIXM 5.1 messages, enabled with the `aixm` feature.
+//!
+//! Each feature `TimeSlice` carries a `gml:validTime` whose begin position
+//! should, for AIRAC changes, be 0000 UTC on a cycle's effective date.
+
+use std::collections::BTreeSet;
+use std::io::BufRead;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::{AiracError, AIRAC};
+
+/// A feature time slice found in an AIXM message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimeSlice {
+    /// The feature type, e.g. `AirportHeliport`.
+    pub feature: String,
+    /// The `gml:id` of the time slice, if any.
+    pub gml_id: Option<String>,
+    /// The start of the time slice's `gml:validTime`.
+    pub begin: DateTime<Utc>,
+    /// The start of the feature's `aixm:featureLifetime`, if given.
+    pub lifetime_begin: Option<DateTime<Utc>>,
+    /// The cycle in effect at `begin`.
+    pub airac: AIRAC,
+}
+
+impl TimeSlice {
+    /// Returns true if the time slice begins at an AIRAC effective instant,
+    /// 0000 UTC on a cycle's start date.
+    pub fn is_effective_instant(&self) -> bool {
+        self.begin == self.airac.starts_at()
+    }
+
+    /// Returns true unless the feature lifetime begins other than at an
+    /// AIRAC effective instant.
+    pub fn is_lifetime_effective_instant(&self) -> bool {
+        self.lifetime_begin
+            .is_none_or(|begin| AIRAC::try_at(begin).is_ok_and(|airac| airac.starts_at() == begin))
+    }
+}
+
+/// The time slices in an AIXM message.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    /// Every time slice with a valid time, in document order.
+    pub time_slices: Vec<TimeSlice>,
+}
+
+impl Summary {
+    /// Reads an AIXM message, streaming rather than loading it all at once.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, AiracError> {
+        let mut reader = Reader::from_reader(reader);
+        let mut buf = Vec::new();
+        let mut time_slices = Vec::new();
+        // The time slice being read, and where within it the reader is.
+        let mut slice: Option<PartialSlice> = None;
+        let mut path: Vec<Vec<u8>> = Vec::new();
+        // The text of the time position being read, which comments or
+        // entities may split across several events.
+        let mut position: Option<String> = None;
+
+        loop {
+            match reader.read_event_into(&mut buf).map_err(invalid)? {
+                Event::Start(e) => {
+                    let name = e.local_name().as_ref().to_vec();
+                    if slice.is_none() && name.ends_with(b"TimeSlice") {
+                        let gml_id = e
+                            .attributes()
+                            .filter_map(Result::ok)
+                            .find(|a| a.key.local_name().as_ref() == b"id")
+                            .map(|a| String::from_utf8_lossy(&a.value).into_owned());
+                        let feature = &name[..name.len() - b"TimeSlice".len()];
+                        slice = Some(PartialSlice {
+                            feature: String::from_utf8_lossy(feature).into_owned(),
+                            gml_id,
+                            begin: None,
+                            lifetime_begin: None,
+                        });
+                        path.clear();
+                    } else if slice.is_some() {
+                        if name == b"beginPosition" || name == b"timePosition" {
+                            position = Some(String::new());
+                        }
+                        path.push(name);
+                    }
+                }
+                // Elements nested in a time slice close first, leaving the
+                // time slice's own end tag once the path is empty.
+                Event::End(_) if !path.is_empty() => {
+                    if let (Some(slice), Some(text)) = (slice.as_mut(), position.take()) {
+                        let in_element = |name: &[u8]| path.iter().any(|p| p == name);
+                        if in_element(b"validTime") && slice.begin.is_none() {
+                            slice.begin = Some(parse_time(text.trim())?);
+                        } else if in_element(b"featureLifetime") && slice.lifetime_begin.is_none() {
+                            slice.lifetime_begin = Some(parse_time(text.trim())?);
+                        }
+                    }
+                    path.pop();
+                }
+                Event::End(_) => {
+                    if let Some(PartialSlice {
+                        feature,
+                        gml_id,
+                        begin: Some(begin),
+                        lifetime_begin,
+                    }) = slice.take()
+                    {
+                        time_slices.push(TimeSlice {
+                            feature,
+                            gml_id,
+                            begin,
+                            lifetime_begin,
+                            airac: AIRAC::try_at(begin)?,
+                        });
+                    }
+                }
+                Event::Text(t) => {
+                    if let Some(position) = position.as_mut() {
+                        position.push_str(&t.unescape().map_err(invalid)?);
+                    }
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+            buf.clear();
+        }
+        Ok(Self { time_slices })
+    }
+
+    /// Reads an AIXM message from a string.
+    pub fn from_contents(contents: &str) -> Result<Self, AiracError> {
+        Self::from_reader(contents.as_bytes())
+    }
+
+    /// The cycles that time slices in the message begin in.
+    pub fn cycles(&self) -> BTreeSet<AIRAC> {
+        self.time_slices.iter().map(|slice| slice.airac).collect()
+    }
+
+    /// The time slices that, or whose feature lifetime, do not begin at an
+    /// AIRAC effective instant.
+    pub fn misaligned(&self) -> impl Iterator<Item = &TimeSlice> {
+        self.time_slices
+            .iter()
+            .filter(|slice| !slice.is_effective_instant() || !slice.is_lifetime_effective_instant())
+    }
+}
+
+struct PartialSlice {
+    feature: String,
+    gml_id: Option<String>,
+    begin: Option<DateTime<Utc>>,
+    lifetime_begin: Option<DateTime<Utc>>,
+}
+
+fn invalid(e: impl std::fmt::Display) -> AiracError {
+    AiracError::InvalidAixm(e.to_string())
+}
+
+/// Parses a GML time position, taking times without an offset as UTC.
+fn parse_time(text: &str) -> Result<DateTime<Utc>, AiracError> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(text) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f")
+        .map(|time| time.and_utc())
+        .map_err(|_| invalid(format!("invalid time position {:?}", text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<message:AIXMBasicMessage xmlns:message="http://www.aixm.aero/schema/5.1/message"
+    xmlns:aixm="http://www.aixm.aero/schema/5.1" xmlns:gml="http://www.opengis.net/gml/3.2"
+    gml:id="M1">
+  <message:hasMember>
+    <aixm:AirportHeliport gml:id="AH1">
+      <aixm:timeSlice>
+        <aixm:AirportHeliportTimeSlice gml:id="AH1-TS1">
+          <gml:validTime>
+            <gml:TimePeriod gml:id="AH1-TP1">
+              <gml:beginPosition>2022-05-19T00:00:00Z</gml:beginPosition>
+              <gml:endPosition indeterminatePosition="unknown"/>
+            </gml:TimePeriod>
+          </gml:validTime>
+          <aixm:interpretation>BASELINE</aixm:interpretation>
+          <aixm:featureLifetime>
+            <gml:TimePeriod gml:id="AH1-TP2">
+              <gml:beginPosition>2020-01-02T00:00:00Z</gml:beginPosition>
+              <gml:endPosition indeterminatePosition="unknown"/>
+            </gml:TimePeriod>
+          </aixm:featureLifetime>
+          <aixm:designator>EGLL</aixm:designator>
+        </aixm:AirportHeliportTimeSlice>
+      </aixm:timeSlice>
+    </aixm:AirportHeliport>
+  </message:hasMember>
+  <message:hasMember>
+    <aixm:Runway gml:id="RWY1">
+      <aixm:timeSlice>
+        <aixm:RunwayTimeSlice gml:id="RWY1-TS1">
+          <gml:validTime>
+            <gml:TimePeriod gml:id="RWY1-TP1">
+              <gml:beginPosition>2022-06-16T00:00:00</gml:beginPosition>
+              <gml:endPosition indeterminatePosition="unknown"/>
+            </gml:TimePeriod>
+          </gml:validTime>
+        </aixm:RunwayTimeSlice>
+      </aixm:timeSlice>
+      <aixm:timeSlice>
+        <aixm:RunwayTimeSlice gml:id="RWY1-TS2">
+          <gml:validTime>
+            <gml:TimeInstant gml:id="RWY1-TI1">
+              <gml:timePosition>2022-06-20T10:00:00+02:00</gml:timePosition>
+            </gml:TimeInstant>
+          </gml:validTime>
+        </aixm:RunwayTimeSlice>
+      </aixm:timeSlice>
+    </aixm:Runway>
+  </message:hasMember>
+</message:AIXMBasicMessage>
+"#;
+
+    #[test]
+    fn test_time_slices() {
+        let summary = Summary::from_contents(MESSAGE).unwrap();
+        let slices = &summary.time_slices;
+        assert_eq!(slices.len(), 3);
+
+        assert_eq!(slices[0].feature, "AirportHeliport");
+        assert_eq!(slices[0].gml_id.as_deref(), Some("AH1-TS1"));
+        assert_eq!(slices[0].airac.to_string(), "2205");
+        assert_eq!(
+            slices[0].lifetime_begin,
+            Some("2001".parse::<AIRAC>().unwrap().starts_at())
+        );
+        assert!(slices[0].is_effective_instant());
+        assert!(slices[0].is_lifetime_effective_instant());
+
+        assert_eq!(slices[1].feature, "Runway");
+        assert_eq!(slices[1].airac.to_string(), "2206");
+        assert_eq!(slices[1].lifetime_begin, None);
+        assert!(slices[1].is_effective_instant());
+        assert!(slices[1].is_lifetime_effective_instant());
+
+        assert_eq!(slices[2].gml_id.as_deref(), Some("RWY1-TS2"));
+        assert_eq!(slices[2].begin.to_rfc3339(), "2022-06-20T08:00:00+00:00");
+        assert_eq!(slices[2].airac.to_string(), "2206");
+        assert!(!slices[2].is_effective_instant());
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = Summary::from_contents(MESSAGE).unwrap();
+        let cycles: Vec<String> = summary.cycles().iter().map(|a| a.to_string()).collect();
+        assert_eq!(cycles, ["2205", "2206"]);
+        let misaligned: Vec<_> = summary.misaligned().collect();
+        assert_eq!(misaligned.len(), 1);
+        assert_eq!(misaligned[0].gml_id.as_deref(), Some("RWY1-TS2"));
+    }
+
+    #[test]
+    fn test_misaligned_lifetime() {
+        let message = MESSAGE.replace("2020-01-02T00:00:00Z", "2020-01-03T00:00:00Z");
+        let summary = Summary::from_contents(&message).unwrap();
+        assert!(summary.time_slices[0].is_effective_instant());
+        assert!(!summary.time_slices[0].is_lifetime_effective_instant());
+        let misaligned: Vec<_> = summary
+            .misaligned()
+            .filter_map(|s| s.gml_id.as_deref())
+            .collect();
+        assert_eq!(misaligned, ["AH1-TS1", "RWY1-TS2"]);
+    }
+
+    #[test]
+    fn test_split_text() {
+        let message = MESSAGE.replace(
+            "2022-05-19T00:00:00Z",
+            "2022-05-19<!-- c -->T00:00:00&#x5A;",
+        );
+        assert_eq!(
+            Summary::from_contents(&message),
+            Summary::from_contents(MESSAGE)
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let message = MESSAGE.replace("2022-05-19T00:00:00Z", "next thursday");
+        assert!(matches!(
+            Summary::from_contents(&message),
+            Err(AiracError::InvalidAixm(_))
+        ));
+        let message = MESSAGE.replace("</aixm:Runway>", "");
+        assert!(Summary::from_contents(&message).is_err());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(Summary::from_contents("").unwrap(), Summary::default());
+    }
+}
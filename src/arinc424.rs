@@ -0,0 +1,209 @@
+//! Cycle fields in ARINC 424 navigation data files, enabled with the
+//! `arinc424` feature.
+//!
+//! Every primary and continuation record carries the cycle it was produced
+//! for as a `YYCC` field in columns 129 to 132, and the `HDR01` header record
+//! carries it in columns 36 to 39.
+
+use std::io::{self, BufRead};
+
+use crate::{AiracError, AIRAC};
+
+/// The byte range of the cycle field in a record.
+const RECORD_CYCLE: std::ops::Range<usize> = 128..132;
+/// The byte range of the cycle field in a `HDR01` record.
+const HEADER_CYCLE: std::ops::Range<usize> = 35..39;
+
+/// Returns the cycle field of a `HDR01` header record, or `None` if the line
+/// is not one.
+pub fn header_cycle(line: &str) -> Option<Result<AIRAC, AiracError>> {
+    if !line.starts_with("HDR01") {
+        return None;
+    }
+    Some(parse_field(line.get(HEADER_CYCLE)))
+}
+
+/// Returns the cycle field of a standard or tailored record, or `None` if the
+/// line is not one. A record too short to have the field is an error.
+pub fn record_cycle(line: &str) -> Option<Result<AIRAC, AiracError>> {
+    if !(line.starts_with('S') || line.starts_with('T')) {
+        return None;
+    }
+    Some(parse_field(line.get(RECORD_CYCLE)))
+}
+
+fn parse_field(field: Option<&str>) -> Result<AIRAC, AiracError> {
+    let field = field.ok_or_else(|| AiracError::InvalidIdentifier(String::new()))?;
+    AIRAC::from_ident(field)
+}
+
+/// A record whose cycle field does not match the file's cycle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    /// The line number of the record, starting from 1.
+    pub line: usize,
+    /// The cycle field as written, which may not be a valid identifier, or
+    /// empty if the record is too short to have one.
+    pub found: String,
+}
+
+/// The cycles found in an ARINC 424 file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// The cycle of the file: that of the `HDR01` record if present and
+    /// valid, otherwise that of the first record with a valid cycle field.
+    pub cycle: Option<AIRAC>,
+    /// The number of header and primary records checked.
+    pub records: usize,
+    /// The records whose cycle field is invalid or differs from `cycle`.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Report {
+    /// Checks every record in an ARINC 424 file.
+    pub fn from_reader(reader: impl BufRead) -> io::Result<Self> {
+        let mut fields = Vec::new();
+        let mut header = None;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let (cycle, range) = match (header_cycle(&line), record_cycle(&line)) {
+                (Some(cycle), _) => {
+                    if header.is_none() {
+                        header = cycle.clone().ok();
+                    }
+                    (cycle, HEADER_CYCLE)
+                }
+                (None, Some(cycle)) => (cycle, RECORD_CYCLE),
+                (None, None) => continue,
+            };
+            let found = line.get(range).unwrap_or_default().to_string();
+            fields.push((i + 1, found, cycle.ok()));
+        }
+
+        let cycle = header.or_else(|| fields.iter().find_map(|(_, _, cycle)| *cycle));
+        let mismatches = fields
+            .iter()
+            .filter(|(_, _, found)| *found != cycle)
+            .map(|(line, found, _)| Mismatch {
+                line: *line,
+                found: found.clone(),
+            })
+            .collect();
+        Ok(Self {
+            cycle,
+            records: fields.len(),
+            mismatches,
+        })
+    }
+
+    /// Checks every record in the contents of an ARINC 424 file.
+    pub fn from_contents(contents: &str) -> Self {
+        Self::from_reader(contents.as_bytes()).expect("reading from a string cannot fail")
+    }
+
+    /// Returns true if a cycle was found and every record agrees with it.
+    pub fn is_consistent(&self) -> bool {
+        self.cycle.is_some() && self.mismatches.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(cycle: &str) -> String {
+        // File name, version, production flag, record length and count.
+        format!(
+            "HDR01{:<15}{:03}P{:04}{:07}{}  01-JAN-2022 00:00:00",
+            "FAACIFP18", 1, 132, 3847, cycle
+        )
+    }
+
+    fn record(ident: &str, cycle: &str) -> String {
+        let mut record = format!("SUSAD {:<122}", ident);
+        record.push_str("00001");
+        record.truncate(128);
+        record.push_str(cycle);
+        record
+    }
+
+    #[test]
+    fn test_fields() {
+        assert_eq!(&header("2205")[HEADER_CYCLE], "2205");
+        let kjfk = record("KJFK", "2205");
+        assert_eq!(kjfk.len(), 132);
+        assert_eq!(record_cycle(&kjfk), Some("2205".parse()));
+        assert_eq!(header_cycle(&header("2205")), Some("2205".parse()));
+        assert_eq!(header_cycle(&kjfk), None);
+        assert_eq!(record_cycle("HDR02 something"), None);
+        assert!(matches!(record_cycle("SUSA short"), Some(Err(_))));
+        assert!(matches!(
+            record_cycle(&record("KJFK", "22XX")),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn test_consistent() {
+        let file = [
+            header("2205"),
+            record("KJFK", "2205"),
+            record("KLGA", "2205"),
+        ]
+        .join("\n");
+        let report = Report::from_contents(&file);
+        assert_eq!(report.cycle, Some("2205".parse().unwrap()));
+        assert_eq!(report.records, 3);
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let file = [
+            header("2205"),
+            "HDR02 comment line".to_string(),
+            record("KJFK", "2205"),
+            record("KLGA", "2204"),
+            record("KEWR", "22XX"),
+            record("KBOS", "2205")[..100].to_string(),
+        ]
+        .join("\r\n");
+        let report = Report::from_contents(&file);
+        assert_eq!(report.cycle, Some("2205".parse().unwrap()));
+        assert_eq!(report.records, 5);
+        assert!(!report.is_consistent());
+        assert_eq!(
+            report.mismatches,
+            [
+                Mismatch {
+                    line: 4,
+                    found: "2204".to_string()
+                },
+                Mismatch {
+                    line: 5,
+                    found: "22XX".to_string()
+                },
+                Mismatch {
+                    line: 6,
+                    found: String::new()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_header() {
+        let file = [record("KJFK", "2206"), record("KLGA", "2205")].join("\n");
+        let report = Report::from_contents(&file);
+        assert_eq!(report.cycle, Some("2206".parse().unwrap()));
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].line, 2);
+    }
+
+    #[test]
+    fn test_empty() {
+        let report = Report::from_contents("");
+        assert_eq!(report, Report::default());
+        assert!(!report.is_consistent());
+    }
+}
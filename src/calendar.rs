@@ -0,0 +1,248 @@
+//! Working day calendars, for moving deadlines off weekends and public
+//! holidays.
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::AiracError;
+
+/// Which way to move a date that does not fall on a working day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Roll {
+    /// Move to the preceding working day.
+    Preceding,
+    /// Move to the following working day.
+    Following,
+}
+
+/// A date that may have been moved onto a working day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Adjusted {
+    /// The date before adjustment.
+    pub original: NaiveDate,
+    /// The working day after adjustment.
+    pub date: NaiveDate,
+}
+
+impl Adjusted {
+    /// Returns true if the date was moved.
+    pub fn is_adjusted(&self) -> bool {
+        self.original != self.date
+    }
+}
+
+/// A definition of working days: every day except the weekend and holidays.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BusinessCalendar {
+    weekend: BTreeSet<u32>,
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl BusinessCalendar {
+    /// Returns a calendar with a Saturday and Sunday weekend and no holidays.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the days of the weekend.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every day of the week is in the weekend.
+    pub fn weekend(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekend = days
+            .into_iter()
+            .map(|day| day.num_days_from_monday())
+            .collect();
+        assert!(
+            self.weekend.len() < 7,
+            "a week must have at least one working day"
+        );
+        self
+    }
+
+    /// Adds a holiday.
+    pub fn holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Adds several holidays.
+    pub fn holidays(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(dates);
+        self
+    }
+
+    /// Returns a calendar with a Saturday and Sunday weekend and a holiday on
+    /// every day covered by the events in an iCalendar file. Recurrence rules
+    /// are not expanded.
+    pub fn from_ics(ics: &str) -> Result<Self, AiracError> {
+        let mut holidays = BTreeSet::new();
+        let mut start = None;
+        let mut end = None;
+        for line in unfold(ics) {
+            let (name, value) = line.split_once(':').unwrap_or((&line, ""));
+            let (name, _params) = name.split_once(';').unwrap_or((name, ""));
+            match name.to_ascii_uppercase().as_str() {
+                "BEGIN" if value.eq_ignore_ascii_case("VEVENT") => {
+                    start = None;
+                    end = None;
+                }
+                "DTSTART" => start = Some(parse_ics_date(value)?),
+                "DTEND" => end = Some(parse_ics_date(value)?),
+                "END" if value.eq_ignore_ascii_case("VEVENT") => {
+                    let start = start.ok_or_else(|| {
+                        AiracError::InvalidCalendar("event without DTSTART".to_string())
+                    })?;
+                    // DTEND is exclusive, and defaults to a single day.
+                    let end = end.unwrap_or(start).max(start.succ_opt().unwrap_or(start));
+                    holidays.extend(start.iter_days().take_while(|d| *d < end));
+                }
+                _ => (),
+            }
+        }
+        Ok(Self::new().holidays(holidays))
+    }
+
+    /// Returns true if the date is neither in the weekend nor a holiday.
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        !self
+            .weekend
+            .contains(&date.weekday().num_days_from_monday())
+            && !self.holidays.contains(&date)
+    }
+
+    /// Moves a date onto a working day, if it is not already on one.
+    pub fn roll(&self, date: NaiveDate, roll: Roll) -> Adjusted {
+        let mut adjusted = date;
+        while !self.is_working_day(adjusted) {
+            adjusted = match roll {
+                Roll::Preceding => adjusted.pred_opt(),
+                Roll::Following => adjusted.succ_opt(),
+            }
+            .expect("working day within the supported range");
+        }
+        Adjusted {
+            original: date,
+            date: adjusted,
+        }
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self {
+            weekend: [Weekday::Sat, Weekday::Sun]
+                .iter()
+                .map(|day| day.num_days_from_monday())
+                .collect(),
+            holidays: BTreeSet::new(),
+        }
+    }
+}
+
+/// Joins folded iCalendar content lines.
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Parses the date part of an iCalendar `DATE` or `DATE-TIME` value.
+fn parse_ics_date(value: &str) -> Result<NaiveDate, AiracError> {
+    value
+        .get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or_else(|| AiracError::InvalidCalendar(format!("invalid date {:?}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    #[test]
+    fn test_weekend() {
+        let calendar = BusinessCalendar::new();
+        assert!(calendar.is_working_day(date(2022, 5, 20)));
+        assert!(!calendar.is_working_day(date(2022, 5, 21)));
+        assert!(!calendar.is_working_day(date(2022, 5, 22)));
+
+        let calendar = BusinessCalendar::new().weekend([Weekday::Fri, Weekday::Sat]);
+        assert!(!calendar.is_working_day(date(2022, 5, 20)));
+        assert!(calendar.is_working_day(date(2022, 5, 22)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_no_working_days() {
+        BusinessCalendar::new().weekend([
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+    }
+
+    #[test]
+    fn test_roll() {
+        // Monday 2022-05-02 was a UK bank holiday.
+        let calendar = BusinessCalendar::new().holiday(date(2022, 5, 2));
+        let adjusted = calendar.roll(date(2022, 5, 1), Roll::Preceding);
+        assert_eq!(adjusted.date, date(2022, 4, 29));
+        assert!(adjusted.is_adjusted());
+        let adjusted = calendar.roll(date(2022, 5, 1), Roll::Following);
+        assert_eq!(adjusted.date, date(2022, 5, 3));
+        let adjusted = calendar.roll(date(2022, 5, 3), Roll::Preceding);
+        assert_eq!(adjusted.date, date(2022, 5, 3));
+        assert!(!adjusted.is_adjusted());
+    }
+
+    #[test]
+    fn test_from_ics() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:early-may\r\n\
+            DTSTART;VALUE=DATE:20220502\r\n\
+            SUMMARY:Early May bank\r\n  holiday\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:jubilee\r\n\
+            DTSTART;VALUE=DATE:20220602\r\n\
+            DTEND;VALUE=DATE:20220604\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:timed\r\n\
+            DTSTART:20221226T000000Z\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+        let calendar = BusinessCalendar::from_ics(ics).unwrap();
+        assert_eq!(
+            calendar,
+            BusinessCalendar::new().holidays([
+                date(2022, 5, 2),
+                date(2022, 6, 2),
+                date(2022, 6, 3),
+                date(2022, 12, 26),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_ics_invalid() {
+        let ics = "BEGIN:VEVENT\nDTSTART:tomorrow\nEND:VEVENT\n";
+        assert!(BusinessCalendar::from_ics(ics).is_err());
+        let ics = "BEGIN:VEVENT\nSUMMARY:No date\nEND:VEVENT\n";
+        assert!(BusinessCalendar::from_ics(ics).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use std::env;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+
+use crate::AiracError;
+
+/// The environment variable read by [`EnvClock`], which overrides the current
+/// time for [`AIRAC::current`](crate::AIRAC::current) and the command-line
+/// tool.
+pub const NOW_VAR: &str = "AIRAC_NOW";
+
+/// A source of the current time.
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that is stopped at a fixed instant.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    /// Returns a clock that always reads the given instant.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self(now)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// A clock that runs a fixed offset ahead of, or behind, another clock.
+#[derive(Clone, Copy, Debug)]
+pub struct OffsetClock<C = SystemClock> {
+    clock: C,
+    offset: Duration,
+}
+
+impl<C: Clock> OffsetClock<C> {
+    /// Returns a clock that reads `offset` later than `clock`.
+    pub fn new(clock: C, offset: Duration) -> Self {
+        Self { clock, offset }
+    }
+}
+
+impl<C: Clock> Clock for OffsetClock<C> {
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now() + self.offset
+    }
+}
+
+/// The system clock, unless the [`AIRAC_NOW`](NOW_VAR) environment variable
+/// is set to an RFC 3339 instant or a `YYYY-MM-DD` date (taken as 0000 UTC).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnvClock;
+
+impl EnvClock {
+    /// Returns the current instant, or an error if the environment variable
+    /// is set but cannot be parsed.
+    pub fn try_now(&self) -> Result<DateTime<Utc>, AiracError> {
+        match env::var(NOW_VAR) {
+            Ok(value) => parse_now(&value),
+            Err(_) => Ok(SystemClock.now()),
+        }
+    }
+}
+
+impl Clock for EnvClock {
+    /// # Panics
+    ///
+    /// Panics if the environment variable is set but cannot be parsed.
+    fn now(&self) -> DateTime<Utc> {
+        self.try_now().unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+fn parse_now(value: &str) -> Result<DateTime<Utc>, AiracError> {
+    if let Ok(instant) = DateTime::parse_from_rfc3339(value) {
+        return Ok(instant.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+        .map_err(|_| AiracError::InvalidInstant(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed() {
+        let now = Utc.with_ymd_and_hms(2022, 5, 19, 0, 0, 0).unwrap();
+        assert_eq!(FixedClock::new(now).now(), now);
+    }
+
+    #[test]
+    fn test_offset() {
+        let now = Utc.with_ymd_and_hms(2022, 5, 19, 0, 0, 0).unwrap();
+        let clock = OffsetClock::new(FixedClock::new(now), Duration::days(-1));
+        assert_eq!(clock.now(), now - Duration::days(1));
+        let clock = OffsetClock::new(SystemClock, Duration::days(28));
+        assert!(clock.now() > SystemClock.now());
+    }
+
+    #[test]
+    fn test_parse_now() {
+        let now = Utc.with_ymd_and_hms(2022, 5, 19, 0, 0, 0).unwrap();
+        assert_eq!(parse_now("2022-05-19"), Ok(now));
+        assert_eq!(parse_now("2022-05-19T00:00:00Z"), Ok(now));
+        assert_eq!(parse_now("2022-05-19T10:00:00+10:00"), Ok(now));
+        assert_eq!(
+            parse_now("yesterday"),
+            Err(AiracError::InvalidInstant("yesterday".to_string()))
+        );
+    }
+}
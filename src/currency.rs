@@ -0,0 +1,232 @@
+//! Currency of navigation datasets: whether data for a cycle is usable at a
+//! given instant.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::AIRAC;
+
+/// How long a dataset remains usable after its cycle ends.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CurrencyPolicy {
+    grace: Duration,
+}
+
+impl CurrencyPolicy {
+    /// A policy allowing a dataset to be used for the given number of days
+    /// after its cycle ends. Negative days are treated as no grace period,
+    /// and a grace period too long to represent never ends.
+    pub fn with_grace_days(days: i64) -> Self {
+        Self {
+            grace: Duration::try_days(days.max(0)).unwrap_or(Duration::MAX),
+        }
+    }
+
+    /// A policy with no grace period, as used for IFR.
+    pub fn strict() -> Self {
+        Self::with_grace_days(0)
+    }
+
+    /// The grace period after a cycle ends.
+    pub fn grace(&self) -> Duration {
+        self.grace
+    }
+}
+
+impl Default for CurrencyPolicy {
+    fn default() -> Self {
+        Self::strict()
+    }
+}
+
+/// The currency of a dataset at an instant.
+///
+/// Day counts are whole days, rounded up, so a dataset expiring at 0000 UTC
+/// tomorrow has one day remaining, and one that expired an hour ago is one
+/// day overdue.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Currency {
+    /// The dataset's cycle has not yet become effective.
+    NotYetEffective {
+        /// Days until the cycle becomes effective.
+        days_until: i64,
+    },
+    /// The dataset's cycle is in effect.
+    Current {
+        /// Days until the cycle ends.
+        days_remaining: i64,
+    },
+    /// The dataset's cycle has ended, but it is within the grace period.
+    Grace {
+        /// Days since the cycle ended.
+        days_overdue: i64,
+        /// Days until the grace period ends.
+        days_remaining: i64,
+    },
+    /// The dataset's cycle and grace period have both ended.
+    Expired {
+        /// Days since the cycle ended.
+        days_overdue: i64,
+    },
+}
+
+impl Currency {
+    /// Evaluates the currency of a dataset for the given cycle at an instant.
+    pub fn evaluate<Tz: TimeZone>(
+        dataset: &AIRAC,
+        instant: DateTime<Tz>,
+        policy: &CurrencyPolicy,
+    ) -> Self {
+        let instant = instant.with_timezone(&Utc);
+        let starts = dataset.starts_at();
+        let ends = dataset.ends_at();
+        // A grace period running past the last representable instant never
+        // ends.
+        let grace_ends = ends
+            .checked_add_signed(policy.grace)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        if instant < starts {
+            Self::NotYetEffective {
+                days_until: days_ceil(starts - instant),
+            }
+        } else if instant < ends {
+            Self::Current {
+                days_remaining: days_ceil(ends - instant),
+            }
+        } else if instant < grace_ends {
+            Self::Grace {
+                days_overdue: days_ceil(instant - ends),
+                days_remaining: days_ceil(grace_ends - instant),
+            }
+        } else {
+            Self::Expired {
+                days_overdue: days_ceil(instant - ends),
+            }
+        }
+    }
+
+    /// Returns true if the dataset may be used: it is current or within its
+    /// grace period.
+    pub fn is_usable(&self) -> bool {
+        matches!(self, Self::Current { .. } | Self::Grace { .. })
+    }
+}
+
+impl AIRAC {
+    /// Evaluates the currency of a dataset for this cycle at an instant.
+    pub fn currency<Tz: TimeZone>(
+        &self,
+        instant: DateTime<Tz>,
+        policy: &CurrencyPolicy,
+    ) -> Currency {
+        Currency::evaluate(self, instant, policy)
+    }
+}
+
+fn days_ceil(duration: Duration) -> i64 {
+    let days = duration.num_days();
+    if duration > Duration::days(days) {
+        days + 1
+    } else {
+        days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::*;
+    use crate::test_util::airac;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_not_yet_effective() {
+        let strict = CurrencyPolicy::strict();
+        assert_eq!(
+            airac().currency(at(2022, 5, 18, 12), &strict),
+            Currency::NotYetEffective { days_until: 1 }
+        );
+        assert_eq!(
+            airac().currency(at(2022, 5, 12, 0), &strict),
+            Currency::NotYetEffective { days_until: 7 }
+        );
+    }
+
+    #[test]
+    fn test_current() {
+        let strict = CurrencyPolicy::strict();
+        assert_eq!(
+            airac().currency(at(2022, 5, 19, 0), &strict),
+            Currency::Current { days_remaining: 28 }
+        );
+        assert_eq!(
+            airac().currency(at(2022, 6, 15, 23), &strict),
+            Currency::Current { days_remaining: 1 }
+        );
+    }
+
+    #[test]
+    fn test_strict_expiry() {
+        let strict = CurrencyPolicy::strict();
+        let currency = airac().currency(at(2022, 6, 16, 0), &strict);
+        assert_eq!(currency, Currency::Expired { days_overdue: 0 });
+        assert!(!currency.is_usable());
+        assert_eq!(
+            airac().currency(at(2022, 6, 16, 23), &strict),
+            Currency::Expired { days_overdue: 1 }
+        );
+        assert_eq!(
+            airac().currency(at(2022, 6, 20, 12), &strict),
+            Currency::Expired { days_overdue: 5 }
+        );
+    }
+
+    #[test]
+    fn test_grace() {
+        let vfr = CurrencyPolicy::with_grace_days(28);
+        let currency = airac().currency(at(2022, 6, 20, 12), &vfr);
+        assert_eq!(
+            currency,
+            Currency::Grace {
+                days_overdue: 5,
+                days_remaining: 24
+            }
+        );
+        assert!(currency.is_usable());
+        assert_eq!(
+            airac().currency(at(2022, 7, 14, 0), &vfr),
+            Currency::Expired { days_overdue: 28 }
+        );
+    }
+
+    #[test]
+    fn test_long_grace() {
+        for days in [100_000_000, i64::MAX / 1000, i64::MAX] {
+            let policy = CurrencyPolicy::with_grace_days(days);
+            let currency = airac().currency(at(2122, 6, 16, 0), &policy);
+            assert!(
+                matches!(currency, Currency::Grace { days_overdue, .. } if days_overdue == 36524),
+                "{:?}",
+                currency
+            );
+        }
+        assert_eq!(
+            CurrencyPolicy::with_grace_days(-7),
+            CurrencyPolicy::strict()
+        );
+    }
+
+    #[test]
+    fn test_timezones() {
+        // 08:00 on 19 May in UTC+10 is before the cycle becomes effective.
+        let tz = FixedOffset::east_opt(10 * 3600).unwrap();
+        let instant = tz.with_ymd_and_hms(2022, 5, 19, 8, 0, 0).unwrap();
+        assert_eq!(
+            airac().currency(instant, &CurrencyPolicy::default()),
+            Currency::NotYetEffective { days_until: 1 }
+        );
+    }
+}
@@ -0,0 +1,379 @@
+//! Data submission cut-off schedules, as published by each State's AIS on top
+//! of the ICAO [milestones](crate::milestones).
+
+use chrono::{Duration, NaiveDate};
+
+use crate::calendar::{BusinessCalendar, Roll};
+use crate::schedule::Schedule;
+use crate::AIRAC;
+
+/// The largest number of days a cut-off may be before or after each
+/// effective date, a century.
+pub const MAX_DAYS_BEFORE: i64 = 36_525;
+
+/// A named cut-off a fixed number of days before each AIRAC effective date.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Cutoff {
+    /// The name of the cut-off, e.g. "Originator data due".
+    pub name: String,
+    /// Days before the effective date. May be negative for dates after it,
+    /// and is at most [`MAX_DAYS_BEFORE`] either way.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_days_before"))]
+    pub days_before: i64,
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_days_before<'de, D: ::serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<i64, D::Error> {
+    let days: i64 = ::serde::Deserialize::deserialize(deserializer)?;
+    if !(-MAX_DAYS_BEFORE..=MAX_DAYS_BEFORE).contains(&days) {
+        return Err(::serde::de::Error::custom(format!(
+            "days_before {} is more than {} days from the effective date",
+            days, MAX_DAYS_BEFORE
+        )));
+    }
+    Ok(days)
+}
+
+/// A cut-off applied to a specific cycle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deadline {
+    /// The cycle this deadline is for.
+    pub airac: AIRAC,
+    /// The name of the cut-off.
+    pub name: String,
+    /// The date of the deadline.
+    pub date: NaiveDate,
+    /// The date before it was moved onto a working day, if it was moved.
+    pub adjusted_from: Option<NaiveDate>,
+}
+
+/// A set of named cut-offs relative to [`AIRAC::starts`].
+///
+/// ```
+/// # use airac::{cutoff::CutoffSchedule, AIRAC};
+/// let schedule = CutoffSchedule::new()
+///     .cutoff("Originator data due", 91)
+///     .cutoff("AIS internal cut-off", 70);
+/// let deadlines = schedule.deadlines(&"2205".parse::<AIRAC>().unwrap());
+/// assert_eq!(deadlines[0].date.to_string(), "2022-02-17");
+/// ```
+///
+/// With the `toml` or `json` features, a schedule can be loaded from a file:
+///
+/// ```toml
+/// [[cutoffs]]
+/// name = "Originator data due"
+/// days_before = 91
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CutoffSchedule {
+    cutoffs: Vec<Cutoff>,
+}
+
+impl CutoffSchedule {
+    /// Returns an empty schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a cut-off `days_before` each effective date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days_before` is more than [`MAX_DAYS_BEFORE`] either way.
+    pub fn cutoff(mut self, name: impl Into<String>, days_before: i64) -> Self {
+        assert!(
+            (-MAX_DAYS_BEFORE..=MAX_DAYS_BEFORE).contains(&days_before),
+            "a cut-off must be within {} days of the effective date",
+            MAX_DAYS_BEFORE
+        );
+        self.cutoffs.push(Cutoff {
+            name: name.into(),
+            days_before,
+        });
+        self
+    }
+
+    /// The cut-offs in this schedule, in the order they were added.
+    pub fn cutoffs(&self) -> &[Cutoff] {
+        &self.cutoffs
+    }
+
+    /// Loads a schedule from TOML, rejecting cut-offs more than
+    /// [`MAX_DAYS_BEFORE`] from the effective date.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    /// Loads a schedule from JSON, rejecting cut-offs more than
+    /// [`MAX_DAYS_BEFORE`] from the effective date.
+    #[cfg(feature = "json")]
+    pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+
+    /// Returns the deadlines for the given cycle, earliest first. Deadlines
+    /// outside the range of representable dates, only possible for the
+    /// first and last supported cycles, are left out.
+    pub fn deadlines(&self, airac: &AIRAC) -> Vec<Deadline> {
+        let mut deadlines: Vec<Deadline> = self
+            .cutoffs
+            .iter()
+            .filter_map(|cutoff| {
+                Some(Deadline {
+                    airac: *airac,
+                    name: cutoff.name.clone(),
+                    date: days_before(airac.starts(), cutoff.days_before)?,
+                    adjusted_from: None,
+                })
+            })
+            .collect();
+        deadlines.sort_by_key(|deadline| deadline.date);
+        deadlines
+    }
+
+    /// Returns the deadlines for the given cycle, earliest first, moved onto
+    /// working days in the given calendar.
+    pub fn deadlines_with(
+        &self,
+        airac: &AIRAC,
+        calendar: &BusinessCalendar,
+        roll: Roll,
+    ) -> Vec<Deadline> {
+        let mut deadlines = self.deadlines(airac);
+        for deadline in &mut deadlines {
+            let adjusted = calendar.roll(deadline.date, roll);
+            if adjusted.is_adjusted() {
+                deadline.date = adjusted.date;
+                deadline.adjusted_from = Some(adjusted.original);
+            }
+        }
+        deadlines.sort_by_key(|deadline| deadline.date);
+        deadlines
+    }
+
+    /// Returns the first deadline on or after the given date, across all
+    /// cycles, or `None` if the schedule is empty.
+    pub fn next_deadline(&self, from: NaiveDate) -> Option<Deadline> {
+        self.next_deadline_by(from, |airac| self.deadlines(airac))
+    }
+
+    /// Returns the first deadline on or after the given date, across all
+    /// cycles, after moving deadlines onto working days in the given
+    /// calendar.
+    pub fn next_deadline_with(
+        &self,
+        from: NaiveDate,
+        calendar: &BusinessCalendar,
+        roll: Roll,
+    ) -> Option<Deadline> {
+        self.next_deadline_by(from, |airac| self.deadlines_with(airac, calendar, roll))
+    }
+
+    fn next_deadline_by(
+        &self,
+        from: NaiveDate,
+        deadlines: impl Fn(&AIRAC) -> Vec<Deadline>,
+    ) -> Option<Deadline> {
+        let min = self.cutoffs.iter().map(|c| c.days_before).min()?;
+        let max = self.cutoffs.iter().map(|c| c.days_before).max()?;
+        let original = |d: &Deadline| d.adjusted_from.unwrap_or(d.date);
+
+        // Find the first cycle with a deadline on or after `from`. Rolling
+        // onto a working day never reorders dates, so this is at most a few
+        // cycles before the first unadjusted one.
+        let start = match days_before(from, -min) {
+            Some(start) => start,
+            // Before the first date, so start from the first whole cycle.
+            None if min < 0 => NaiveDate::MIN + Duration::days(AIRAC::PERIOD_DAYS - 1),
+            None => return None,
+        };
+        let mut airac = AIRAC::try_from_date(start).ok()?;
+        while let Ok(previous) = airac.try_previous() {
+            match deadlines(&previous).last() {
+                Some(last) if last.date >= from => airac = previous,
+                _ => break,
+            }
+        }
+
+        let mut best: Option<Deadline> = None;
+        loop {
+            if let Some(best) = &best {
+                // Later cycles only have later deadlines from here.
+                if days_before(airac.starts(), max).is_some_and(|d| d > original(best)) {
+                    break;
+                }
+            }
+            for deadline in deadlines(&airac) {
+                if deadline.date >= from && best.as_ref().is_none_or(|b| deadline.date < b.date) {
+                    best = Some(deadline);
+                }
+            }
+            airac = airac.try_next().ok()?;
+        }
+        best
+    }
+}
+
+/// The date `days` before `date`, or `None` if it cannot be represented.
+fn days_before(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    date.checked_sub_signed(Duration::try_days(days)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    fn schedule() -> CutoffSchedule {
+        CutoffSchedule::new()
+            .cutoff("AIS internal cut-off", 70)
+            .cutoff("Originator data due", 91)
+    }
+
+    #[test]
+    fn test_deadlines() {
+        let airac: AIRAC = "2205".parse().unwrap();
+        let deadlines = schedule().deadlines(&airac);
+        assert_eq!(deadlines.len(), 2);
+        assert_eq!(deadlines[0].name, "Originator data due");
+        assert_eq!(deadlines[0].date, date(2022, 2, 17));
+        assert_eq!(deadlines[1].name, "AIS internal cut-off");
+        assert_eq!(deadlines[1].date, date(2022, 3, 10));
+        assert!(deadlines.iter().all(|d| d.airac == airac));
+    }
+
+    #[test]
+    fn test_next_deadline() {
+        // 2022-02-17 is the originator deadline for 2205.
+        let next = schedule().next_deadline(date(2022, 2, 17)).unwrap();
+        assert_eq!(next.airac.to_string(), "2205");
+        assert_eq!(next.name, "Originator data due");
+
+        // The 2205 internal cut-off on 2022-03-10 comes before the 2206
+        // originator deadline on 2022-03-17.
+        let next = schedule().next_deadline(date(2022, 2, 18)).unwrap();
+        assert_eq!(next.airac.to_string(), "2205");
+        assert_eq!(next.date, date(2022, 3, 10));
+
+        let next = schedule().next_deadline(date(2022, 3, 11)).unwrap();
+        assert_eq!(next.airac.to_string(), "2206");
+        assert_eq!(next.date, date(2022, 3, 17));
+    }
+
+    #[test]
+    fn test_next_deadline_after_effective() {
+        let schedule = CutoffSchedule::new().cutoff("Post-effective review", -7);
+        let next = schedule.next_deadline(date(2022, 5, 20)).unwrap();
+        assert_eq!(next.airac.to_string(), "2205");
+        assert_eq!(next.date, date(2022, 5, 26));
+    }
+
+    #[test]
+    fn test_deadlines_with() {
+        // 2022-02-17 was a Thursday, 2022-03-10 a Thursday.
+        let calendar = BusinessCalendar::new().holiday(date(2022, 2, 17));
+        let airac: AIRAC = "2205".parse().unwrap();
+        let deadlines = schedule().deadlines_with(&airac, &calendar, Roll::Preceding);
+        assert_eq!(deadlines[0].date, date(2022, 2, 16));
+        assert_eq!(deadlines[0].adjusted_from, Some(date(2022, 2, 17)));
+        assert_eq!(deadlines[1].date, date(2022, 3, 10));
+        assert_eq!(deadlines[1].adjusted_from, None);
+
+        let deadlines = schedule().deadlines_with(&airac, &calendar, Roll::Following);
+        assert_eq!(deadlines[0].date, date(2022, 2, 18));
+    }
+
+    #[test]
+    fn test_next_deadline_with() {
+        // 89 days before 2205 is Saturday 2022-02-19, and before 2206 is
+        // Saturday 2022-03-19.
+        let schedule = CutoffSchedule::new().cutoff("Saturday", 89);
+        let calendar = BusinessCalendar::new();
+        assert_eq!(
+            schedule
+                .next_deadline(date(2022, 2, 21))
+                .unwrap()
+                .airac
+                .to_string(),
+            "2206"
+        );
+        let next = schedule
+            .next_deadline_with(date(2022, 2, 21), &calendar, Roll::Following)
+            .unwrap();
+        assert_eq!(next.airac.to_string(), "2205");
+        assert_eq!(next.date, date(2022, 2, 21));
+        assert_eq!(next.adjusted_from, Some(date(2022, 2, 19)));
+
+        let next = schedule
+            .next_deadline_with(date(2022, 2, 19), &calendar, Roll::Preceding)
+            .unwrap();
+        assert_eq!(next.airac.to_string(), "2206");
+        assert_eq!(next.date, date(2022, 3, 18));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(CutoffSchedule::new().next_deadline(date(2022, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_range_limits() {
+        let schedule = CutoffSchedule::new()
+            .cutoff("Long lead", MAX_DAYS_BEFORE)
+            .cutoff("Long review", -MAX_DAYS_BEFORE);
+        let first = AIRAC::try_from_date(NaiveDate::MIN + Duration::days(28)).unwrap();
+        let deadlines = schedule.deadlines(&first);
+        assert_eq!(deadlines.len(), 1);
+        assert_eq!(deadlines[0].name, "Long review");
+        assert!(schedule.next_deadline(NaiveDate::MIN).is_some());
+        assert_eq!(schedule.next_deadline(NaiveDate::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cutoff_out_of_range() {
+        let _ = CutoffSchedule::new().cutoff("Never", MAX_DAYS_BEFORE + 1);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let toml = r#"
+            [[cutoffs]]
+            name = "AIS internal cut-off"
+            days_before = 70
+
+            [[cutoffs]]
+            name = "Originator data due"
+            days_before = 91
+        "#;
+        assert_eq!(CutoffSchedule::from_toml(toml).unwrap(), schedule());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_out_of_range() {
+        for days in ["100000000", "9223372036854775807", "-9223372036854775808"] {
+            let toml = format!("[[cutoffs]]\nname = \"Never\"\ndays_before = {}\n", days);
+            assert!(CutoffSchedule::from_toml(&toml).is_err(), "{}", days);
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_json() {
+        let json = r#"{"cutoffs": [
+            {"name": "AIS internal cut-off", "days_before": 70},
+            {"name": "Originator data due", "days_before": 91}
+        ]}"#;
+        assert_eq!(CutoffSchedule::from_json(json).unwrap(), schedule());
+        let json = r#"{"cutoffs": [{"name": "Never", "days_before": 100000000}]}"#;
+        assert!(CutoffSchedule::from_json(json).is_err());
+    }
+}
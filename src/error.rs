@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt;
+
+use chrono::NaiveDate;
+
+/// Errors that can occur when constructing an [`AIRAC`](crate::AIRAC) or
+/// another [schedule](crate::schedule::Schedule)'s cycle, or when reading
+/// files that refer to cycles. Variants for optional file formats only exist
+/// with their features enabled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AiracError {
+    /// The year, month and day given do not form a valid date.
+    InvalidDate { year: i32, month: u32, day: u32 },
+    /// The AIRAC cycle in effect on this date cannot be represented.
+    OutOfRange(NaiveDate),
+    /// The identifier was not of a recognised form.
+    InvalidIdentifier(String),
+    /// The identifier named a cycle that does not exist in that year.
+    InvalidCycleNumber { year: i32, number: u32 },
+    /// Cycle arithmetic left the supported range.
+    Overflow,
+    /// The instant could not be parsed.
+    InvalidInstant(String),
+    /// An iCalendar file could not be parsed.
+    InvalidCalendar(String),
+    /// An X-Plane `cycle_info.txt` file could not be parsed.
+    #[cfg(feature = "xplane")]
+    InvalidCycleInfo(String),
+    /// An AIXM message could not be read.
+    #[cfg(feature = "aixm")]
+    InvalidAixm(String),
+}
+
+impl fmt::Display for AiracError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDate { year, month, day } => {
+                write!(f, "invalid date {:04}-{:02}-{:02}", year, month, day)
+            }
+            Self::OutOfRange(date) => {
+                write!(f, "the AIRAC cycle in effect on {} is out of range", date)
+            }
+            Self::InvalidIdentifier(ident) => {
+                write!(f, "invalid AIRAC identifier {:?}", ident)
+            }
+            Self::InvalidCycleNumber { year, number } => {
+                write!(f, "there is no AIRAC cycle {:02} in {}", number, year)
+            }
+            Self::Overflow => write!(f, "AIRAC cycle out of the supported range"),
+            Self::InvalidInstant(instant) => write!(f, "invalid instant {:?}", instant),
+            Self::InvalidCalendar(reason) => write!(f, "invalid iCalendar file: {}", reason),
+            #[cfg(feature = "xplane")]
+            Self::InvalidCycleInfo(reason) => write!(f, "invalid cycle_info.txt: {}", reason),
+            #[cfg(feature = "aixm")]
+            Self::InvalidAixm(reason) => write!(f, "invalid AIXM message: {}", reason),
+        }
+    }
+}
+
+impl Error for AiracError {}
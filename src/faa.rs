@@ -0,0 +1,241 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::prelude::*;
+
+use crate::schedule::Schedule;
+use crate::{AiracError, CenturyPivot, AIRAC};
+
+/// An FAA 56-day chart edition, on which VFR sectionals, IFR enroute charts
+/// and the Chart Supplement are published.
+///
+/// Editions start on every other AIRAC effective date, including 2002, and
+/// are named by that date, e.g. `25 JAN 2024`. Unlike AIRAC cycles, they
+/// take effect at 0901 UTC.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct Faa56Day(NaiveDate);
+
+impl Faa56Day {
+    /// The FAA 56-day edition in effect during the given AIRAC cycle. Every
+    /// AIRAC cycle falls within a single edition.
+    pub fn for_airac(airac: &AIRAC) -> Self {
+        <Self as Schedule>::try_from_date(airac.starts())
+            .expect("every AIRAC cycle is within a supported edition")
+    }
+
+    /// The edition starting on the effective date of the given AIRAC cycle,
+    /// or `None` if an edition is in effect from the previous cycle.
+    pub fn starting_with(airac: &AIRAC) -> Option<Self> {
+        let edition = Self::for_airac(airac);
+        (edition.starts() == airac.starts()).then_some(edition)
+    }
+
+    /// The AIRAC cycle this edition starts with.
+    pub fn airac(&self) -> AIRAC {
+        AIRAC::try_from_date(self.0).expect("every edition starts on an AIRAC date")
+    }
+}
+
+/// FAA 56-day editions, from the edition starting with AIRAC 2002 on
+/// 2020-01-30.
+impl Schedule for Faa56Day {
+    const ANCHOR: NaiveDate = NaiveDate::from_ymd_opt(2020, 1, 30).unwrap();
+    const PERIOD_DAYS: i64 = 56;
+    const EFFECTIVE_TIME: NaiveTime = NaiveTime::from_hms_opt(9, 1, 0).unwrap();
+
+    fn from_start(starts: NaiveDate) -> Self {
+        Self(starts)
+    }
+
+    fn starts(&self) -> NaiveDate {
+        self.0
+    }
+
+    /// The edition name, its effective date, e.g. `25 JAN 2024`.
+    fn ident(&self) -> String {
+        self.0.format("%d %b %Y").to_string().to_uppercase()
+    }
+
+    /// The edition name, which already has a four digit year.
+    fn full_ident(&self) -> String {
+        self.ident()
+    }
+
+    /// Parses an edition name, e.g. `25 JAN 2024`, in any case. The name has
+    /// a four digit year, so the pivot is not used.
+    fn from_ident_with(ident: &str, _pivot: CenturyPivot) -> Result<Self, AiracError> {
+        let invalid = || AiracError::InvalidIdentifier(ident.to_string());
+        let date = NaiveDate::parse_from_str(ident, "%d %b %Y").map_err(|_| invalid())?;
+        let edition = <Self as Schedule>::try_from_date(date)?;
+        if edition.starts() != date {
+            return Err(invalid());
+        }
+        Ok(edition)
+    }
+
+    /// Parses an edition name, as for
+    /// [`from_ident`](Schedule::from_ident).
+    fn from_full_ident(ident: &str) -> Result<Self, AiracError> {
+        Self::from_ident(ident)
+    }
+}
+
+/// Displays the edition name, e.g. `25 JAN 2024`, or with `{:#}` the
+/// effective period as printed on charts, e.g. `EFFECTIVE 0901Z 25 JAN 2024
+/// TO 0901Z 21 MAR 2024`. Width, fill and alignment are honoured.
+impl fmt::Display for Faa56Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ident = self.ident();
+        if f.alternate() {
+            let next = self.ends().format("%d %b %Y").to_string().to_uppercase();
+            let time = Self::EFFECTIVE_TIME.format("%H%MZ");
+            f.pad(&format!(
+                "EFFECTIVE {} {} TO {} {}",
+                time, ident, time, next
+            ))
+        } else {
+            f.pad(&ident)
+        }
+    }
+}
+
+impl fmt::Debug for Faa56Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Faa56Day")
+            .field("edition", &self.to_string())
+            .field("starts", &self.starts())
+            .field("ends", &self.ends())
+            .finish()
+    }
+}
+
+impl From<Faa56Day> for AIRAC {
+    fn from(edition: Faa56Day) -> Self {
+        edition.airac()
+    }
+}
+
+impl FromStr for Faa56Day {
+    type Err = AiracError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_ident(s)
+    }
+}
+
+impl AIRAC {
+    /// Returns true if an FAA 56-day edition starts on this cycle's
+    /// effective date.
+    pub fn is_faa_56_day(&self) -> bool {
+        Faa56Day::starting_with(self).is_some()
+    }
+
+    /// The FAA 56-day edition in effect during this cycle.
+    pub fn faa_56_day(&self) -> Faa56Day {
+        Faa56Day::for_airac(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+
+    #[test]
+    fn test_editions_2024() {
+        let starts: Vec<NaiveDate> = Faa56Day::cycles_in_year(2024)
+            .unwrap()
+            .map(|edition| edition.starts())
+            .collect();
+        assert_eq!(
+            starts,
+            [
+                date(2024, 1, 25),
+                date(2024, 3, 21),
+                date(2024, 5, 16),
+                date(2024, 7, 11),
+                date(2024, 9, 5),
+                date(2024, 10, 31),
+                date(2024, 12, 26),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_airac_conversions() {
+        let airac: AIRAC = "2401".parse().unwrap();
+        assert!(airac.is_faa_56_day());
+        assert!(!airac.next().is_faa_56_day());
+        assert!(airac.next().next().is_faa_56_day());
+
+        let edition = airac.faa_56_day();
+        assert_eq!(airac.next().faa_56_day(), edition);
+        assert_eq!(AIRAC::from(edition), airac);
+        assert_eq!(Faa56Day::starting_with(&airac), Some(edition));
+        assert_eq!(Faa56Day::starting_with(&airac.next()), None);
+        assert_eq!(edition.ends(), airac.next().ends());
+    }
+
+    #[test]
+    fn test_every_other_airac() {
+        for airac in AIRAC::cycles_in_year(1990).unwrap().take(1000) {
+            let edition = airac.faa_56_day();
+            assert!(edition.starts() <= airac.starts() && airac.ends() <= edition.ends());
+            assert_eq!(airac.is_faa_56_day(), airac.index().rem_euclid(2) == 1);
+        }
+    }
+
+    #[test]
+    fn test_naming() {
+        let edition: Faa56Day = "25 JAN 2024".parse().unwrap();
+        assert_eq!(edition.starts(), date(2024, 1, 25));
+        assert_eq!(edition.to_string(), "25 JAN 2024");
+        assert_eq!(
+            format!("{:#}", edition),
+            "EFFECTIVE 0901Z 25 JAN 2024 TO 0901Z 21 MAR 2024"
+        );
+        assert_eq!(format!("{:>12}", edition), " 25 JAN 2024");
+        assert_eq!("25 Jan 2024".parse(), Ok(edition));
+        assert_eq!(edition.format("%{ident}").to_string(), "25 JAN 2024");
+        assert_eq!(edition.full_ident(), "25 JAN 2024");
+        assert_eq!(edition.format("%{full}").to_string(), "25 JAN 2024");
+        assert_eq!(Faa56Day::from_full_ident("25 JAN 2024"), Ok(edition));
+        assert_eq!(
+            Faa56Day::from_ident_with("25 JAN 2024", CenturyPivot::Fixed(2000)),
+            Ok(edition)
+        );
+    }
+
+    #[test]
+    fn test_effective_time() {
+        let edition: Faa56Day = "25 JAN 2024".parse().unwrap();
+        let effective = Utc.with_ymd_and_hms(2024, 1, 25, 9, 1, 0).unwrap();
+        assert_eq!(edition.starts_at(), effective);
+        assert_eq!(edition.ends_at().date_naive(), date(2024, 3, 21));
+        assert_eq!(Faa56Day::try_at(effective), Ok(edition));
+        let before = Utc.with_ymd_and_hms(2024, 1, 25, 5, 0, 0).unwrap();
+        assert_eq!(Faa56Day::try_at(before).unwrap().ends(), edition.starts());
+        assert!(!edition.contains_instant(before));
+        assert!(edition.contains_instant(effective));
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        for name in [
+            "22 FEB 2024",
+            "2401",
+            "202401",
+            "2024-01",
+            "30 FEB 2024",
+            "",
+        ] {
+            let invalid = Err(AiracError::InvalidIdentifier(name.to_string()));
+            assert_eq!(name.parse::<Faa56Day>(), invalid);
+            assert_eq!(Faa56Day::from_full_ident(name), invalid);
+            assert_eq!(
+                Faa56Day::from_ident_with(name, CenturyPivot::default()),
+                invalid
+            );
+        }
+    }
+}
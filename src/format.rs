@@ -0,0 +1,169 @@
+use std::fmt::{self, Write};
+
+use crate::schedule::Schedule;
+use crate::AIRAC;
+
+/// A lazily formatted cycle, returned by [`AIRAC::format`] and
+/// [`Schedule::format`].
+#[derive(Clone, Debug)]
+pub struct CycleFormat<'a, C = AIRAC> {
+    cycle: C,
+    pattern: &'a str,
+}
+
+impl<'a, C: Schedule> CycleFormat<'a, C> {
+    pub(crate) fn new(cycle: C, pattern: &'a str) -> Self {
+        Self { cycle, pattern }
+    }
+}
+
+impl AIRAC {
+    /// Formats this cycle with a pattern, which is only interpreted when the
+    /// result is displayed.
+    ///
+    /// Cycle specifiers are written in braces:
+    ///
+    /// | Specifier   | Example  | Description                              |
+    /// |-------------|----------|------------------------------------------|
+    /// | `%{ident}`  | `2205`   | The `YYNN` identifier                    |
+    /// | `%{full}`   | `202205` | The [full identifier](AIRAC::full_ident) |
+    /// | `%{number}` | `05`     | The number within the year               |
+    /// | `%{yy}`     | `22`     | The two digit year                       |
+    /// | `%{yyyy}`   | `2022`   | The four digit year                      |
+    /// | `%{index}`  | `31`     | The [index](AIRAC::index)                |
+    /// | `%%`        | `%`      | A literal percent sign                   |
+    ///
+    /// Any other specifier is a [`chrono` date
+    /// specifier](chrono::format::strftime) applied to the
+    /// [start](AIRAC::starts) date, or the [end](AIRAC::ends) date if prefixed
+    /// with `E`, e.g. `%d` or `%Ed`. A `^` after the `%` or `E` upper-cases
+    /// the result, e.g. `%^b` for `MAY`.
+    ///
+    /// As with `chrono`, displaying an invalid pattern returns
+    /// [`fmt::Error`], so `to_string()` panics.
+    ///
+    /// ```
+    /// # use airac::AIRAC;
+    /// let airac: AIRAC = "2205".parse().unwrap();
+    /// assert_eq!(
+    ///     airac.format("AIRAC %{ident} (%d %^b %Y – %Ed %E^b %EY)").to_string(),
+    ///     "AIRAC 2205 (19 MAY 2022 – 16 JUN 2022)",
+    /// );
+    /// assert_eq!(airac.format("AMDT %{number}/%{yy}").to_string(), "AMDT 05/22");
+    /// assert_eq!(airac.format("%{yyyy}-%{number}").to_string(), "2022-05");
+    /// ```
+    pub fn format<'a>(&self, pattern: &'a str) -> CycleFormat<'a> {
+        CycleFormat::new(*self, pattern)
+    }
+}
+
+impl<C: Schedule> fmt::Display for CycleFormat<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cycle = &self.cycle;
+        let mut rest = self.pattern;
+        while let Some(i) = rest.find('%') {
+            f.write_str(&rest[..i])?;
+            rest = &rest[i + 1..];
+
+            if let Some(r) = rest.strip_prefix('%') {
+                f.write_char('%')?;
+                rest = r;
+                continue;
+            }
+
+            if let Some(r) = rest.strip_prefix('{') {
+                let end = r.find('}').ok_or(fmt::Error)?;
+                match &r[..end] {
+                    "ident" => f.write_str(&cycle.ident())?,
+                    "full" => f.write_str(&cycle.full_ident())?,
+                    "number" => write!(f, "{:02}", cycle.number())?,
+                    "yy" => write!(f, "{:02}", cycle.year().rem_euclid(100))?,
+                    "yyyy" => write!(f, "{:04}", cycle.year())?,
+                    "index" => write!(f, "{}", cycle.index())?,
+                    _ => return Err(fmt::Error),
+                }
+                rest = &r[end + 1..];
+                continue;
+            }
+
+            let (date, r) = match rest.strip_prefix('E') {
+                Some(r) => (cycle.ends(), r),
+                None => (cycle.starts(), rest),
+            };
+            let (upper, r) = match r.strip_prefix('^') {
+                Some(r) => (true, r),
+                None => (false, r),
+            };
+            // An optional padding flag, then a single letter.
+            let len = match r.as_bytes() {
+                [b'-' | b'_' | b'0', c, ..] if c.is_ascii_alphabetic() => 2,
+                [c, ..] if c.is_ascii_alphabetic() => 1,
+                _ => return Err(fmt::Error),
+            };
+            let spec = format!("%{}", &r[..len]);
+            rest = &r[len..];
+
+            let mut formatted = String::new();
+            write!(formatted, "{}", date.format(&spec))?;
+            if upper {
+                formatted = formatted.to_uppercase();
+            }
+            f.write_str(&formatted)?;
+        }
+        f.write_str(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::*;
+    use crate::test_util::airac;
+
+    #[test]
+    fn test_cycle_specifiers() {
+        assert_eq!(
+            airac()
+                .format("%{ident} %{full} %{number} %{yy} %{yyyy} %{index} 100%%")
+                .to_string(),
+            "2205 202205 05 22 2022 31 100%"
+        );
+        assert_eq!(
+            AIRAC::from_year_and_number(2001, 1)
+                .unwrap()
+                .format("%{yy}%{number}")
+                .to_string(),
+            "0101"
+        );
+    }
+
+    #[test]
+    fn test_date_specifiers() {
+        assert_eq!(
+            airac().format("%Y-%m-%d to %EY-%Em-%Ed").to_string(),
+            "2022-05-19 to 2022-06-16"
+        );
+        assert_eq!(airac().format("%A %-d %^B").to_string(), "Thursday 19 MAY");
+        assert_eq!(airac().format("%E^a %E-m/%Ey").to_string(), "THU 6/22");
+        assert_eq!(airac().format("%j").to_string(), "139");
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(airac().format("").to_string(), "");
+        assert_eq!(airac().format("AIRAC – cycle").to_string(), "AIRAC – cycle");
+    }
+
+    #[test]
+    fn test_invalid() {
+        for pattern in ["%", "%{ident", "%{nope}", "%E", "%^", "%-", "%H", "%!"] {
+            let mut s = String::new();
+            assert!(
+                write!(s, "{}", airac().format(pattern)).is_err(),
+                "{}",
+                pattern
+            );
+        }
+    }
+}
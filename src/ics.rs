@@ -0,0 +1,212 @@
+//! iCalendar (RFC 5545) export of AIRAC cycles, enabled with the `ics`
+//! feature.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::clock::{Clock, SystemClock};
+use crate::AIRAC;
+
+/// Writes AIRAC cycles as iCalendar events.
+///
+/// Each cycle is an all-day event spanning the days it is effective, with a
+/// UID derived from its [full identifier](AIRAC::full_ident) so that
+/// re-importing an updated file replaces rather than duplicates events.
+///
+/// ```
+/// # use airac::{ics::IcsWriter, AIRAC};
+/// let start: AIRAC = "2301".parse().unwrap();
+/// let ics = IcsWriter::new()
+///     .milestones(true)
+///     .write(start.iter_from().take(26));
+/// assert!(ics.contains("UID:202301@airac\r\n"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IcsWriter {
+    milestones: bool,
+    dtstamp: DateTime<Utc>,
+}
+
+impl IcsWriter {
+    /// Returns a writer for cycles without milestones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to include an event for each publication
+    /// [milestone](crate::milestones::Milestones) of each cycle. Cycles too
+    /// early to have milestones get none.
+    pub fn milestones(mut self, include: bool) -> Self {
+        self.milestones = include;
+        self
+    }
+
+    /// Sets the `DTSTAMP` of every event, which defaults to now.
+    pub fn dtstamp(mut self, dtstamp: DateTime<Utc>) -> Self {
+        self.dtstamp = dtstamp;
+        self
+    }
+
+    /// Returns an iCalendar file containing the given cycles.
+    pub fn write(&self, cycles: impl IntoIterator<Item = AIRAC>) -> String {
+        let mut ics = String::new();
+        line(&mut ics, "BEGIN:VCALENDAR");
+        line(&mut ics, "VERSION:2.0");
+        line(&mut ics, "PRODID:-//airac-rs//airac//EN");
+        line(&mut ics, "CALSCALE:GREGORIAN");
+        for airac in cycles {
+            self.event(
+                &mut ics,
+                &airac.full_ident(),
+                airac.starts(),
+                airac.ends(),
+                &format!("AIRAC {}", airac),
+            );
+            // Milestones before the earliest representable date are left
+            // out.
+            let milestones = if self.milestones {
+                airac.try_milestones().ok()
+            } else {
+                None
+            };
+            if let Some(milestones) = milestones {
+                // The trigger NOTAM is issued on the publication date, so is
+                // not a separate event.
+                for (name, summary, date) in [
+                    (
+                        "major-change-publication",
+                        "major change publication",
+                        milestones.major_change_publication,
+                    ),
+                    ("publication", "publication", milestones.publication),
+                    ("receipt", "receipt", milestones.receipt),
+                ] {
+                    self.event(
+                        &mut ics,
+                        &format!("{}-{}", airac.full_ident(), name),
+                        date,
+                        date.succ_opt().expect("milestones are before the end date"),
+                        &format!("AIRAC {} {}", airac, summary),
+                    );
+                }
+            }
+        }
+        line(&mut ics, "END:VCALENDAR");
+        ics
+    }
+
+    fn event(&self, ics: &mut String, uid: &str, start: NaiveDate, end: NaiveDate, summary: &str) {
+        line(ics, "BEGIN:VEVENT");
+        line(ics, &format!("UID:{}@airac", uid));
+        line(
+            ics,
+            &format!("DTSTAMP:{}", self.dtstamp.format("%Y%m%dT%H%M%SZ")),
+        );
+        line(
+            ics,
+            &format!("DTSTART;VALUE=DATE:{}", start.format("%Y%m%d")),
+        );
+        line(ics, &format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        line(ics, &format!("SUMMARY:{}", summary));
+        line(ics, "TRANSP:TRANSPARENT");
+        line(ics, "END:VEVENT");
+    }
+}
+
+impl Default for IcsWriter {
+    fn default() -> Self {
+        Self {
+            milestones: false,
+            dtstamp: SystemClock.now(),
+        }
+    }
+}
+
+/// Writes a content line, folding it at 75 octets.
+fn line(ics: &mut String, content: &str) {
+    let mut start = 0;
+    let mut width = 75;
+    while content.len() - start > width {
+        let mut end = start + width;
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        let _ = write!(ics, "{}\r\n ", &content[start..end]);
+        start = end;
+        // Continuation lines begin with a space.
+        width = 74;
+    }
+    let _ = write!(ics, "{}\r\n", &content[start..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    fn writer() -> IcsWriter {
+        IcsWriter::new().dtstamp(Utc.with_ymd_and_hms(2022, 5, 1, 12, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_write() {
+        let airac: AIRAC = "2205".parse().unwrap();
+        assert_eq!(
+            writer().write([airac]),
+            "BEGIN:VCALENDAR\r\n\
+             VERSION:2.0\r\n\
+             PRODID:-//airac-rs//airac//EN\r\n\
+             CALSCALE:GREGORIAN\r\n\
+             BEGIN:VEVENT\r\n\
+             UID:202205@airac\r\n\
+             DTSTAMP:20220501T120000Z\r\n\
+             DTSTART;VALUE=DATE:20220519\r\n\
+             DTEND;VALUE=DATE:20220616\r\n\
+             SUMMARY:AIRAC 2205\r\n\
+             TRANSP:TRANSPARENT\r\n\
+             END:VEVENT\r\n\
+             END:VCALENDAR\r\n"
+        );
+    }
+
+    #[test]
+    fn test_milestones() {
+        let airac: AIRAC = "2205".parse().unwrap();
+        let ics = writer().milestones(true).write([airac]);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 4);
+        assert!(ics.contains(
+            "UID:202205-publication@airac\r\n\
+             DTSTAMP:20220501T120000Z\r\n\
+             DTSTART;VALUE=DATE:20220407\r\n\
+             DTEND;VALUE=DATE:20220408\r\n\
+             SUMMARY:AIRAC 2205 publication\r\n"
+        ));
+
+        let first = AIRAC::try_from_date(NaiveDate::MIN + Duration::days(27)).unwrap();
+        let ics = writer().milestones(true).write([first]);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+    }
+
+    #[test]
+    fn test_fold() {
+        let mut ics = String::new();
+        line(&mut ics, &"x".repeat(160));
+        let lines: Vec<&str> = ics.split("\r\n").collect();
+        assert_eq!(lines[0].len(), 75);
+        assert_eq!(lines[1].len(), 75);
+        assert_eq!(lines[2].len(), 12);
+        assert_eq!(lines[3], "");
+    }
+
+    #[test]
+    fn test_round_trip_as_holidays() {
+        let cycles = AIRAC::cycles_in_year(2022).unwrap();
+        let ics = writer().write(cycles.clone());
+        let calendar = crate::calendar::BusinessCalendar::from_ics(&ics).unwrap();
+        for airac in cycles {
+            assert!(!calendar.is_working_day(airac.starts()));
+        }
+    }
+}
@@ -1,59 +1,226 @@
 use chrono::prelude::*;
-use chrono::Duration;
-use lazy_static::lazy_static;
 
 use std::fmt;
+use std::str::FromStr;
 
 pub use chrono::{Datelike, NaiveDate};
 
-lazy_static! {
-    static ref START_DATE: NaiveDate = NaiveDate::from_ymd(2020, 1, 2);
-    static ref CYCLE_LENGTH: Duration = Duration::days(28);
-}
+#[cfg(feature = "aixm")]
+pub mod aixm;
+#[cfg(feature = "arinc424")]
+pub mod arinc424;
+pub mod calendar;
+pub mod clock;
+use clock::{Clock, EnvClock};
+pub mod currency;
+pub mod cutoff;
+mod error;
+pub use error::AiracError;
+mod faa;
+pub use faa::Faa56Day;
+mod format;
+pub use format::CycleFormat;
+#[cfg(feature = "ics")]
+pub mod ics;
+pub mod milestones;
+mod ops;
+#[cfg(feature = "parse")]
+pub mod parse;
+mod pivot;
+pub use pivot::CenturyPivot;
+mod range;
+pub use range::{AiracRange, CycleRange};
+pub mod schedule;
+use schedule::Schedule;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(test)]
+mod test_util;
+#[cfg(feature = "xplane")]
+pub mod xplane;
 
 /// A representation ICAO defined AIRAC cycle.
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct AIRAC(NaiveDate);
 
 impl AIRAC {
-    /// Returns the AIRAC cycle valid on the day given
+    /// Returns the AIRAC cycle valid on the day given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid or out of the supported range. See
+    /// [`AIRAC::try_from_ymd`] for a fallible version.
     pub fn from_ymd(y: i32, m: u32, d: u32) -> Self {
-        let mut airac_date = START_DATE.clone();
-        let target = NaiveDate::from_ymd(y, m, d);
-        if y < 2020 {
-            // Move backward in time
-            loop {
-                airac_date -= *CYCLE_LENGTH;
-                if airac_date < target {
-                    break;
-                }
-            }
-        } else {
-            // Move forward in time
-            loop {
-                if airac_date + *CYCLE_LENGTH > target {
-                    break;
-                }
-                airac_date += *CYCLE_LENGTH;
-            }
-        }
-        Self(airac_date)
+        Self::try_from_ymd(y, m, d).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the AIRAC cycle valid on the day given, or an error if the
+    /// date is invalid or out of the supported range.
+    pub fn try_from_ymd(y: i32, m: u32, d: u32) -> Result<Self, AiracError> {
+        let date = NaiveDate::from_ymd_opt(y, m, d).ok_or(AiracError::InvalidDate {
+            year: y,
+            month: m,
+            day: d,
+        })?;
+        Self::try_from_date(date)
+    }
+
+    /// Returns the AIRAC cycle valid on the date given, or an error if the
+    /// cycle cannot be represented.
+    pub fn try_from_date(target: NaiveDate) -> Result<Self, AiracError> {
+        <Self as Schedule>::try_from_date(target)
+    }
+
+    /// Returns the AIRAC cycle with the given [index](AIRAC::index).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cycle is out of the supported range. See
+    /// [`AIRAC::try_from_index`] for a fallible version.
+    pub fn from_index(index: i32) -> Self {
+        Self::try_from_index(index).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the AIRAC cycle with the given [index](AIRAC::index), or
+    /// [`AiracError::Overflow`] if it is out of the supported range.
+    pub fn try_from_index(index: i32) -> Result<Self, AiracError> {
+        <Self as Schedule>::try_from_index(index)
+    }
+
+    /// Returns the AIRAC cycle with the given number within a year, e.g.
+    /// `(2022, 5)` for 2205.
+    pub fn from_year_and_number(year: i32, number: u32) -> Result<Self, AiracError> {
+        <Self as Schedule>::from_year_and_number(year, number)
+    }
+
+    /// Returns the AIRAC cycle in effect at the given instant. The instant
+    /// is converted to UTC first, as cycles change at 0000 UTC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cycle is out of the supported range. See
+    /// [`AIRAC::try_at`] for a fallible version.
+    pub fn at<Tz: TimeZone>(instant: DateTime<Tz>) -> Self {
+        Self::try_at(instant).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the AIRAC cycle in effect at the given instant, or an error if
+    /// the cycle cannot be represented.
+    pub fn try_at<Tz: TimeZone>(instant: DateTime<Tz>) -> Result<Self, AiracError> {
+        <Self as Schedule>::try_at(instant)
     }
 
-    /// Get the current active AIRAC
+    /// Get the current active AIRAC.
+    ///
+    /// The current time can be overridden with the
+    /// [`AIRAC_NOW`](clock::NOW_VAR) environment variable, see
+    /// [`EnvClock`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `AIRAC_NOW` is set but cannot be parsed, or the cycle is out
+    /// of the supported range. See [`AIRAC::try_current`] for a fallible
+    /// version.
     pub fn current() -> Self {
-        let today = Utc::today().naive_utc();
-        Self::from_ymd(today.year(), today.month(), today.day())
+        Self::current_with(&EnvClock)
+    }
+
+    /// Get the current active AIRAC, or an error if
+    /// [`AIRAC_NOW`](clock::NOW_VAR) is set but cannot be parsed or the cycle
+    /// cannot be represented.
+    pub fn try_current() -> Result<Self, AiracError> {
+        Self::try_at(EnvClock.try_now()?)
+    }
+
+    /// Get the AIRAC active at the time read from the given clock.
+    pub fn current_with(clock: &impl Clock) -> Self {
+        Self::at(clock.now())
+    }
+
+    /// Parses an AIRAC identifier of the form `YYNN`, e.g. `"2205"`.
+    ///
+    /// Two digit years are interpreted with the default [`CenturyPivot`], as
+    /// for POSIX `strptime`: `69` to `99` are in the 1900s, `00` to `68` in
+    /// the 2000s.
+    pub fn from_ident(ident: &str) -> Result<Self, AiracError> {
+        Self::from_ident_with(ident, CenturyPivot::default())
+    }
+
+    /// Parses an AIRAC identifier of the form `YYNN`, choosing the century
+    /// with the given [`CenturyPivot`].
+    pub fn from_ident_with(ident: &str, pivot: CenturyPivot) -> Result<Self, AiracError> {
+        <Self as Schedule>::from_ident_with(ident, pivot)
+    }
+
+    /// Parses an identifier with a four digit year, either `YYYYNN` or
+    /// `YYYY-NN`, e.g. `"202205"` or `"2022-05"`.
+    pub fn from_full_ident(ident: &str) -> Result<Self, AiracError> {
+        <Self as Schedule>::from_full_ident(ident)
+    }
+
+    /// The identifier of this cycle with a four digit year, e.g. `"202205"`.
+    /// Unlike the `YYNN` form from [`Display`](fmt::Display), this is not
+    /// ambiguous between centuries.
+    pub fn full_ident(&self) -> String {
+        <Self as Schedule>::full_ident(self)
     }
 
     /// Returns the previous AIRAC cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the previous cycle is out of the supported range.
     pub fn previous(&self) -> Self {
-        Self(self.0 - *CYCLE_LENGTH)
+        self.try_previous().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the previous AIRAC cycle, or [`AiracError::Overflow`] if it is
+    /// out of the supported range.
+    pub fn try_previous(&self) -> Result<Self, AiracError> {
+        self.checked_sub(1).ok_or(AiracError::Overflow)
     }
 
     /// Returns the next AIRAC cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the next cycle is out of the supported range.
     pub fn next(&self) -> Self {
-        Self(self.0 + *CYCLE_LENGTH)
+        self.try_next().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the next AIRAC cycle, or [`AiracError::Overflow`] if it is out
+    /// of the supported range.
+    pub fn try_next(&self) -> Result<Self, AiracError> {
+        self.checked_add(1).ok_or(AiracError::Overflow)
+    }
+
+    /// Returns the cycles that start in the given year, of which there are
+    /// 13 or 14.
+    pub fn cycles_in_year(year: i32) -> Result<AiracRange, AiracError> {
+        <Self as Schedule>::cycles_in_year(year)
+    }
+
+    /// Returns an iterator over this cycle and every cycle after it.
+    pub fn iter_from(&self) -> AiracRange {
+        <Self as Schedule>::iter_from(self)
+    }
+
+    /// The year this AIRAC started in, e.g. 2022 for 2205.
+    pub fn year(&self) -> i32 {
+        <Self as Schedule>::year(self)
+    }
+
+    /// The number of this AIRAC within its year, from 1 to 14, e.g. 5 for
+    /// 2205.
+    pub fn number(&self) -> u32 {
+        <Self as Schedule>::number(self)
+    }
+
+    /// The number of cycles since cycle 2001 (effective 2020-01-02), which
+    /// has index 0. Cycles before 2001 have negative indices.
+    pub fn index(&self) -> i32 {
+        <Self as Schedule>::index(self)
     }
 
     /// The date that this AIRAC stared on.
@@ -65,32 +232,98 @@ impl AIRAC {
     /// For the avoidance of doubt, the AIRAC became ineffective as this day
     /// began.
     pub fn ends(&self) -> NaiveDate {
-        self.0 + *CYCLE_LENGTH
+        <Self as Schedule>::ends(self)
+    }
+
+    /// The instant this AIRAC became effective, at 0000 UTC on
+    /// [`starts`](AIRAC::starts).
+    pub fn starts_at(&self) -> DateTime<Utc> {
+        <Self as Schedule>::starts_at(self)
+    }
+
+    /// The instant this AIRAC became ineffective, at 0000 UTC on
+    /// [`ends`](AIRAC::ends).
+    pub fn ends_at(&self) -> DateTime<Utc> {
+        <Self as Schedule>::ends_at(self)
+    }
+
+    /// Returns true if this AIRAC was in effect at the given instant.
+    pub fn contains_instant<Tz: TimeZone>(&self, instant: DateTime<Tz>) -> bool {
+        <Self as Schedule>::contains_instant(self, instant)
+    }
+}
+
+/// The ICAO schedule, 28 day cycles from 2001 starting on 2020-01-02.
+impl Schedule for AIRAC {
+    const ANCHOR: NaiveDate = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+    const PERIOD_DAYS: i64 = 28;
+
+    fn from_start(starts: NaiveDate) -> Self {
+        Self(starts)
+    }
+
+    fn starts(&self) -> NaiveDate {
+        self.0
     }
 }
 
+/// Displays the `YYNN` identifier, e.g. `2205`, or with `{:#}` a long form,
+/// e.g. `AIRAC 2205 effective 2022-05-19`. Width, fill and alignment are
+/// honoured.
 impl fmt::Display for AIRAC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut airac_count = 0;
-        let mut a = self.clone();
-        loop {
-            a = a.previous();
-            if a.starts().year() != self.starts().year() {
-                break;
-            }
-            airac_count += 1;
+        let ident = <Self as Schedule>::ident(self);
+        if f.alternate() {
+            f.pad(&format!("AIRAC {} effective {}", ident, self.0))
+        } else {
+            f.pad(&ident)
         }
-        write!(f, "{}{:02}", self.0.format("%y"), airac_count + 1)
+    }
+}
+
+impl fmt::Debug for AIRAC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AIRAC")
+            .field("ident", &self.to_string())
+            .field("starts", &self.starts())
+            .field("ends", &self.ends())
+            .finish()
+    }
+}
+
+impl TryFrom<NaiveDate> for AIRAC {
+    type Error = AiracError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        Self::try_from_date(date)
+    }
+}
+
+impl TryFrom<&str> for AIRAC {
+    type Error = AiracError;
+
+    fn try_from(ident: &str) -> Result<Self, Self::Error> {
+        Self::from_ident(ident)
+    }
+}
+
+impl FromStr for AIRAC {
+    type Err = AiracError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_ident(s)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Duration;
 
     #[test]
     fn test_ord() {
-        let current = AIRAC::current();
+        let now = Utc.with_ymd_and_hms(2022, 5, 19, 0, 0, 0).unwrap();
+        let current = AIRAC::current_with(&clock::FixedClock::new(now));
         let prev = current.previous();
         assert!(prev < current);
         let next = current.next();
@@ -105,27 +338,264 @@ mod tests {
 
     #[test]
     fn test_started_and_ends_correct_pre_2020() {
-        let airac = AIRAC::from_ymd(2018, 02, 17);
-        assert_eq!(airac.starts(), NaiveDate::from_ymd(2018, 02, 01));
-        assert_eq!(airac.ends(), NaiveDate::from_ymd(2018, 03, 01));
+        let airac = AIRAC::from_ymd(2018, 2, 17);
+        assert_eq!(airac.starts(), NaiveDate::from_ymd_opt(2018, 2, 1).unwrap());
+        assert_eq!(airac.ends(), NaiveDate::from_ymd_opt(2018, 3, 1).unwrap());
     }
 
     #[test]
     fn test_started_and_ends_correct_post_2020() {
-        let airac = AIRAC::from_ymd(2022, 05, 23);
-        assert_eq!(airac.starts(), NaiveDate::from_ymd(2022, 05, 19));
-        assert_eq!(airac.ends(), NaiveDate::from_ymd(2022, 06, 16));
+        let airac = AIRAC::from_ymd(2022, 5, 23);
+        assert_eq!(
+            airac.starts(),
+            NaiveDate::from_ymd_opt(2022, 5, 19).unwrap()
+        );
+        assert_eq!(airac.ends(), NaiveDate::from_ymd_opt(2022, 6, 16).unwrap());
     }
 
     #[test]
     fn test_airac_pre2020() {
-        let airac = AIRAC::from_ymd(2019, 05, 13);
+        let airac = AIRAC::from_ymd(2019, 5, 13);
         assert_eq!("1905", format!("{}", airac));
     }
 
     #[test]
     fn test_airac_post2020() {
-        let airac = AIRAC::from_ymd(2022, 05, 23);
+        let airac = AIRAC::from_ymd(2022, 5, 23);
         assert_eq!("2205", format!("{}", airac));
     }
+
+    #[test]
+    fn test_cycle_start_dates() {
+        for (y, m, d) in [(2019, 12, 5), (2019, 11, 7), (2020, 1, 2), (2020, 1, 30)] {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            assert_eq!(AIRAC::from_ymd(y, m, d).starts(), date);
+        }
+    }
+
+    #[test]
+    fn test_day_before_epoch() {
+        let airac = AIRAC::from_ymd(2020, 1, 1);
+        assert_eq!(
+            airac.starts(),
+            NaiveDate::from_ymd_opt(2019, 12, 5).unwrap()
+        );
+        assert_eq!("1913", format!("{}", airac));
+    }
+
+    #[test]
+    fn test_every_day_1990_to_2100() {
+        let epoch = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+        let end = NaiveDate::from_ymd_opt(2100, 12, 31).unwrap();
+        let mut date = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+        while date <= end {
+            let airac = AIRAC::try_from_date(date).unwrap();
+            assert!(airac.starts() <= date && date < airac.ends(), "{}", date);
+            assert_eq!((airac.starts() - epoch).num_days() % 28, 0, "{}", date);
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let airac = AIRAC::from_ymd(2022, 5, 23);
+        assert_eq!(airac.year(), 2022);
+        assert_eq!(airac.number(), 5);
+        assert_eq!(AIRAC::from_ymd(2020, 12, 31).number(), 14);
+        assert_eq!(AIRAC::from_ymd(2019, 1, 3).number(), 1);
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(AIRAC::from_ymd(2020, 1, 2).index(), 0);
+        assert_eq!(AIRAC::from_ymd(2020, 1, 1).index(), -1);
+        assert_eq!(AIRAC::from_ymd(2022, 5, 23).index(), 31);
+        for index in -1000..1000 {
+            let airac = AIRAC::from_index(index);
+            assert_eq!(airac.index(), index);
+            assert_eq!(airac.next(), AIRAC::from_index(index + 1));
+        }
+        assert_eq!(AIRAC::try_from_index(i32::MAX), Err(AiracError::Overflow));
+        assert_eq!(AIRAC::try_from_index(i32::MIN), Err(AiracError::Overflow));
+    }
+
+    #[test]
+    fn test_from_year_and_number() {
+        assert_eq!(
+            AIRAC::from_year_and_number(2022, 5),
+            Ok(AIRAC::from_ymd(2022, 5, 23))
+        );
+        assert_eq!(
+            AIRAC::from_year_and_number(2020, 14),
+            Ok(AIRAC::from_ymd(2020, 12, 31))
+        );
+        assert!(AIRAC::from_year_and_number(2021, 14).is_err());
+        assert!(AIRAC::from_year_and_number(2021, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_instants() {
+        let airac = AIRAC::from_ymd(2022, 5, 23);
+        assert_eq!(airac.starts_at().to_rfc3339(), "2022-05-19T00:00:00+00:00");
+        assert_eq!(airac.ends_at().to_rfc3339(), "2022-06-16T00:00:00+00:00");
+        assert!(airac.contains_instant(airac.starts_at()));
+        assert!(!airac.contains_instant(airac.ends_at()));
+        assert!(airac.contains_instant(airac.ends_at() - Duration::nanoseconds(1)));
+    }
+
+    #[test]
+    fn test_at_converts_to_utc() {
+        // 08:00 on the effective date in UTC+10 is still the previous day in
+        // UTC.
+        let tz = FixedOffset::east_opt(10 * 3600).unwrap();
+        let instant = tz.with_ymd_and_hms(2022, 5, 19, 8, 0, 0).unwrap();
+        let airac = AIRAC::at(instant);
+        assert_eq!(airac.to_string(), "2204");
+        assert!(airac.contains_instant(instant));
+        assert_eq!(AIRAC::at(instant + Duration::hours(2)).to_string(), "2205");
+
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let instant = tz.with_ymd_and_hms(2022, 5, 18, 19, 0, 0).unwrap();
+        assert_eq!(AIRAC::at(instant).to_string(), "2205");
+    }
+
+    #[test]
+    fn test_current_with() {
+        let now = Utc.with_ymd_and_hms(2022, 5, 19, 0, 0, 0).unwrap();
+        let clock = clock::FixedClock::new(now);
+        assert_eq!(AIRAC::current_with(&clock).to_string(), "2205");
+        let clock = clock::OffsetClock::new(clock, -Duration::nanoseconds(1));
+        assert_eq!(AIRAC::current_with(&clock).to_string(), "2204");
+    }
+
+    #[test]
+    fn test_try_from_ymd() {
+        assert_eq!(
+            AIRAC::try_from_ymd(2022, 5, 23),
+            Ok(AIRAC::from_ymd(2022, 5, 23))
+        );
+        assert_eq!(
+            AIRAC::try_from_ymd(2022, 2, 30),
+            Err(AiracError::InvalidDate {
+                year: 2022,
+                month: 2,
+                day: 30
+            })
+        );
+        assert!(AIRAC::try_from_ymd(2022, 13, 1).is_err());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let date = NaiveDate::from_ymd_opt(2022, 5, 23).unwrap();
+        assert_eq!(AIRAC::try_from(date), Ok(AIRAC::from_ymd(2022, 5, 23)));
+        assert_eq!(AIRAC::try_from("2205"), Ok(AIRAC::from_ymd(2022, 5, 23)));
+        assert_eq!(
+            AIRAC::try_from(NaiveDate::MAX),
+            Err(AiracError::OutOfRange(NaiveDate::MAX))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_ymd_invalid_panics() {
+        AIRAC::from_ymd(2022, 2, 30);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let last = AIRAC::try_from_date(NaiveDate::MAX - Duration::days(28)).unwrap();
+        assert_eq!(last.try_next(), Err(AiracError::Overflow));
+        assert!(last.try_previous().is_ok());
+    }
+
+    #[test]
+    fn test_display_flags() {
+        let airac = AIRAC::from_ymd(2022, 5, 23);
+        assert_eq!(format!("{:#}", airac), "AIRAC 2205 effective 2022-05-19");
+        assert_eq!(format!("[{:>6}]", airac), "[  2205]");
+        assert_eq!(format!("[{:*<6}]", airac), "[2205**]");
+        assert_eq!(format!("[{:^8}]", airac), "[  2205  ]");
+        assert_eq!(
+            format!("[{:>#34}]", airac),
+            "[   AIRAC 2205 effective 2022-05-19]"
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let airac = AIRAC::from_ymd(2022, 5, 23);
+        assert_eq!(
+            format!("{:?}", airac),
+            r#"AIRAC { ident: "2205", starts: 2022-05-19, ends: 2022-06-16 }"#
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let airac: AIRAC = "2205".parse().unwrap();
+        assert_eq!(
+            airac.starts(),
+            NaiveDate::from_ymd_opt(2022, 5, 19).unwrap()
+        );
+        let airac: AIRAC = "1901".parse().unwrap();
+        assert_eq!(airac.starts(), NaiveDate::from_ymd_opt(2019, 1, 3).unwrap());
+        assert_eq!(AIRAC::from_ident("2014").unwrap().to_string(), "2014");
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let mut airac = AIRAC::from_ymd(2018, 1, 1);
+        for _ in 0..100 {
+            assert_eq!(airac.to_string().parse::<AIRAC>().unwrap(), airac);
+            airac = airac.next();
+        }
+    }
+
+    #[test]
+    fn test_full_ident() {
+        let airac = AIRAC::from_ymd(2022, 5, 23);
+        assert_eq!(airac.full_ident(), "202205");
+        assert_eq!(AIRAC::from_full_ident("202205"), Ok(airac));
+        assert_eq!(AIRAC::from_full_ident("2022-05"), Ok(airac));
+        assert_eq!(
+            AIRAC::from_full_ident("1998-05").unwrap().to_string(),
+            "9805"
+        );
+        for ident in [
+            "2205", "2022_05", "20220", "2022-5", "+02205", "202214", "123é5", "2022é5",
+        ] {
+            assert!(AIRAC::from_full_ident(ident).is_err(), "{}", ident);
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            "2214".parse::<AIRAC>(),
+            Err(AiracError::InvalidCycleNumber {
+                year: 2022,
+                number: 14
+            })
+        );
+        assert_eq!(
+            "2200".parse::<AIRAC>(),
+            Err(AiracError::InvalidCycleNumber {
+                year: 2022,
+                number: 0
+            })
+        );
+        assert!(matches!(
+            "22O5".parse::<AIRAC>(),
+            Err(AiracError::InvalidIdentifier(_))
+        ));
+        assert!(matches!(
+            "22051".parse::<AIRAC>(),
+            Err(AiracError::InvalidIdentifier(_))
+        ));
+        assert!(matches!(
+            "+205".parse::<AIRAC>(),
+            Err(AiracError::InvalidIdentifier(_))
+        ));
+    }
 }
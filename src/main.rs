@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::process::ExitCode;
+
+use airac::clock::EnvClock;
+use airac::ics::IcsWriter;
+use airac::{AiracError, AiracRange, NaiveDate, AIRAC};
+use clap::{Parser, Subcommand};
+use serde_json::{json, Value};
+
+/// Look up AIRAC cycles.
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Print JSON instead of human readable text: an object for `current`,
+    /// `at` and `info`, and an array for `next`, `previous` and `between`.
+    /// Not supported by `ics`.
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show the cycle in effect today, or at the AIRAC_NOW environment
+    /// variable if set.
+    Current,
+    /// Show the cycle in effect on a date (YYYY-MM-DD).
+    At { date: String },
+    /// Show the cycle with an identifier (YYNN or YYYYNN).
+    Info { ident: String },
+    /// Show the cycles following the current one.
+    Next {
+        /// The number of cycles to show.
+        #[arg(short = 'n', long, default_value_t = 1)]
+        count: usize,
+    },
+    /// Show the cycles preceding the current one.
+    Previous {
+        /// The number of cycles to show.
+        #[arg(short = 'n', long, default_value_t = 1)]
+        count: usize,
+    },
+    /// Show every cycle from one identifier to another, inclusive.
+    Between { from: String, to: String },
+    /// Write cycles as an iCalendar file.
+    Ics {
+        /// The first cycle to write, by default the current one.
+        #[arg(long)]
+        from: Option<String>,
+        /// The number of cycles to write.
+        #[arg(short = 'n', long, default_value_t = 13)]
+        count: usize,
+        /// Include publication milestones for each cycle.
+        #[arg(long)]
+        milestones: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match run(args.command, args.json) {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command, json: bool) -> Result<String, Box<dyn Error>> {
+    let single = matches!(
+        command,
+        Command::Current | Command::At { .. } | Command::Info { .. }
+    );
+    let cycles = match command {
+        Command::Current => vec![AIRAC::try_current()?],
+        Command::At { date } => {
+            let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")?;
+            vec![AIRAC::try_from_date(date)?]
+        }
+        Command::Info { ident } => vec![parse_ident(&ident)?],
+        Command::Next { count } => {
+            let cycles: Vec<AIRAC> = AIRAC::try_current()?
+                .iter_from()
+                .skip(1)
+                .take(count)
+                .collect();
+            if cycles.len() < count {
+                return Err(AiracError::Overflow.into());
+            }
+            cycles
+        }
+        Command::Previous { count } => {
+            // Not preallocated, as the count is only bounded by running out
+            // of cycles.
+            let mut cycles = Vec::new();
+            let mut airac = AIRAC::try_current()?;
+            for _ in 0..count {
+                airac = airac.try_previous()?;
+                cycles.push(airac);
+            }
+            cycles
+        }
+        Command::Between { from, to } => {
+            AiracRange::inclusive(parse_ident(&from)?, parse_ident(&to)?).collect()
+        }
+        Command::Ics {
+            from,
+            count,
+            milestones,
+        } => {
+            if json {
+                return Err("--json is not supported by ics".into());
+            }
+            let from = match from {
+                Some(from) => parse_ident(&from)?,
+                None => AIRAC::try_current()?,
+            };
+            let writer = IcsWriter::new()
+                .milestones(milestones)
+                .dtstamp(EnvClock.try_now()?);
+            return Ok(writer.write(from.iter_from().take(count)));
+        }
+    };
+
+    if json {
+        let value = match &cycles[..] {
+            [one] if single => to_json(one),
+            _ => Value::Array(cycles.iter().map(to_json).collect()),
+        };
+        Ok(format!("{}\n", value))
+    } else {
+        Ok(cycles
+            .iter()
+            .map(|airac| format!("{} {} {}\n", airac, airac.starts(), airac.ends()))
+            .collect())
+    }
+}
+
+fn parse_ident(ident: &str) -> Result<AIRAC, AiracError> {
+    if ident.len() == 4 {
+        AIRAC::from_ident(ident)
+    } else {
+        AIRAC::from_full_ident(ident)
+    }
+}
+
+fn to_json(airac: &AIRAC) -> Value {
+    json!({
+        "ident": airac,
+        "full_ident": airac.full_ident(),
+        "year": airac.year(),
+        "number": airac.number(),
+        "index": airac.index(),
+        "starts": airac.starts().to_string(),
+        "ends": airac.ends().to_string(),
+    })
+}
@@ -0,0 +1,187 @@
+//! AIP publication and distribution milestones, as defined by ICAO Annex 15
+//! and Doc 8126.
+
+use chrono::{Days, NaiveDate};
+
+use crate::calendar::{Adjusted, BusinessCalendar, Roll};
+use crate::schedule::Schedule;
+use crate::{AiracError, AIRAC};
+
+/// Days before the effective date by which AIRAC information must be
+/// published and distributed.
+pub const PUBLICATION_DAYS: i64 = 42;
+/// Days before the effective date by which major changes must be published
+/// and distributed.
+pub const MAJOR_CHANGE_PUBLICATION_DAYS: i64 = 56;
+/// Days before the effective date by which AIRAC information must be
+/// received by recipients.
+pub const RECEIPT_DAYS: i64 = 28;
+
+/// The milestone dates leading up to an AIRAC effective date.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Milestones {
+    /// The date major changes must be published and distributed by.
+    pub major_change_publication: NaiveDate,
+    /// The date changes must be published and distributed by.
+    pub publication: NaiveDate,
+    /// The date a trigger NOTAM is issued, which is the publication date of
+    /// the AIRAC AIP amendment or supplement.
+    pub trigger_notam: NaiveDate,
+    /// The date changes must be received by recipients.
+    pub receipt: NaiveDate,
+    /// The date the cycle becomes effective.
+    pub effective: NaiveDate,
+}
+
+impl Milestones {
+    /// Returns the milestones for the given AIRAC cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics for the first few supported cycles, whose milestones fall
+    /// before the earliest representable date. See
+    /// [`Milestones::try_for_cycle`] for a fallible version.
+    pub fn for_airac(airac: &AIRAC) -> Self {
+        Self::for_cycle(airac)
+    }
+
+    /// Returns the milestones for a cycle of any [`Schedule`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a milestone falls before the earliest representable date.
+    /// See [`Milestones::try_for_cycle`] for a fallible version.
+    pub fn for_cycle<C: Schedule>(cycle: &C) -> Self {
+        Self::try_for_cycle(cycle).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the milestones for a cycle of any [`Schedule`], or
+    /// [`AiracError::Overflow`] if a milestone falls before the earliest
+    /// representable date.
+    pub fn try_for_cycle<C: Schedule>(cycle: &C) -> Result<Self, AiracError> {
+        let effective = cycle.starts();
+        let before = |days: i64| {
+            effective
+                .checked_sub_days(Days::new(days.unsigned_abs()))
+                .ok_or(AiracError::Overflow)
+        };
+        Ok(Self {
+            major_change_publication: before(MAJOR_CHANGE_PUBLICATION_DAYS)?,
+            publication: before(PUBLICATION_DAYS)?,
+            trigger_notam: before(PUBLICATION_DAYS)?,
+            receipt: before(RECEIPT_DAYS)?,
+            effective,
+        })
+    }
+}
+
+/// A milestone that was moved onto a working day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Adjustment {
+    /// The name of the milestone field, e.g. `"publication"`.
+    pub milestone: &'static str,
+    /// The original and adjusted dates.
+    pub adjusted: Adjusted,
+}
+
+impl Milestones {
+    /// Moves every milestone except the effective date onto a working day in
+    /// the given calendar, returning the milestones that were moved.
+    pub fn adjust(&mut self, calendar: &BusinessCalendar, roll: Roll) -> Vec<Adjustment> {
+        let mut adjustments = Vec::new();
+        for (milestone, date) in [
+            (
+                "major_change_publication",
+                &mut self.major_change_publication,
+            ),
+            ("publication", &mut self.publication),
+            ("trigger_notam", &mut self.trigger_notam),
+            ("receipt", &mut self.receipt),
+        ] {
+            let adjusted = calendar.roll(*date, roll);
+            if adjusted.is_adjusted() {
+                *date = adjusted.date;
+                adjustments.push(Adjustment {
+                    milestone,
+                    adjusted,
+                });
+            }
+        }
+        adjustments
+    }
+}
+
+impl AIRAC {
+    /// Returns the publication milestones for this cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics for the first few supported cycles. See
+    /// [`AIRAC::try_milestones`] for a fallible version.
+    pub fn milestones(&self) -> Milestones {
+        Milestones::for_airac(self)
+    }
+
+    /// Returns the publication milestones for this cycle, or
+    /// [`AiracError::Overflow`] if they fall before the earliest
+    /// representable date.
+    pub fn try_milestones(&self) -> Result<Milestones, AiracError> {
+        Milestones::try_for_cycle(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{airac, date};
+
+    #[test]
+    fn test_milestones() {
+        let milestones = airac().milestones();
+        assert_eq!(
+            milestones,
+            Milestones {
+                major_change_publication: date(2022, 3, 24),
+                publication: date(2022, 4, 7),
+                trigger_notam: date(2022, 4, 7),
+                receipt: date(2022, 4, 21),
+                effective: date(2022, 5, 19),
+            }
+        );
+    }
+
+    #[test]
+    fn test_earliest_cycles() {
+        let first = AIRAC::try_from_date(NaiveDate::MIN + chrono::Duration::days(27)).unwrap();
+        assert_eq!(first.try_milestones(), Err(AiracError::Overflow));
+        // Milestones are at most 56 days, two cycles, before the effective date.
+        let third = first.next().next();
+        assert!(third.starts() - NaiveDate::MIN >= chrono::Duration::days(56));
+        assert_eq!(third.try_milestones(), Ok(third.milestones()));
+    }
+
+    #[test]
+    fn test_adjust() {
+        // Milestones fall on Thursdays, so move them with a Thursday holiday.
+        let calendar = BusinessCalendar::new().holiday(date(2022, 4, 7));
+        let mut milestones = airac().milestones();
+        let adjustments = milestones.adjust(&calendar, Roll::Preceding);
+        assert_eq!(milestones.publication, date(2022, 4, 6));
+        assert_eq!(milestones.trigger_notam, date(2022, 4, 6));
+        assert_eq!(milestones.receipt, date(2022, 4, 21));
+        assert_eq!(adjustments.len(), 2);
+        assert_eq!(adjustments[0].milestone, "publication");
+        assert_eq!(adjustments[0].adjusted.original, date(2022, 4, 7));
+        assert_eq!(adjustments[1].milestone, "trigger_notam");
+    }
+
+    #[test]
+    fn test_receipt_is_previous_cycle_start() {
+        let airac: AIRAC = "2205".parse().unwrap();
+        assert_eq!(airac.milestones().receipt, airac.previous().starts());
+        assert_eq!(
+            airac.milestones().major_change_publication,
+            airac.previous().previous().starts()
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::schedule::Schedule;
+use crate::AIRAC;
+
+impl AIRAC {
+    /// Returns the cycle `cycles` after this one, or `None` if it is out of
+    /// the supported range.
+    pub fn checked_add(self, cycles: i32) -> Option<Self> {
+        <Self as Schedule>::checked_add(self, cycles)
+    }
+
+    /// Returns the cycle `cycles` before this one, or `None` if it is out of
+    /// the supported range.
+    pub fn checked_sub(self, cycles: i32) -> Option<Self> {
+        <Self as Schedule>::checked_sub(self, cycles)
+    }
+}
+
+/// Advances by a number of cycles.
+///
+/// # Panics
+///
+/// Panics if the result is out of the supported range. See
+/// [`AIRAC::checked_add`] for a fallible version.
+impl Add<i32> for AIRAC {
+    type Output = AIRAC;
+
+    fn add(self, cycles: i32) -> AIRAC {
+        self.checked_add(cycles)
+            .expect("AIRAC cycle out of the supported range")
+    }
+}
+
+/// Goes back by a number of cycles.
+///
+/// # Panics
+///
+/// Panics if the result is out of the supported range. See
+/// [`AIRAC::checked_sub`] for a fallible version.
+impl Sub<i32> for AIRAC {
+    type Output = AIRAC;
+
+    fn sub(self, cycles: i32) -> AIRAC {
+        self.checked_sub(cycles)
+            .expect("AIRAC cycle out of the supported range")
+    }
+}
+
+impl AddAssign<i32> for AIRAC {
+    fn add_assign(&mut self, cycles: i32) {
+        *self = *self + cycles;
+    }
+}
+
+impl SubAssign<i32> for AIRAC {
+    fn sub_assign(&mut self, cycles: i32) {
+        *self = *self - cycles;
+    }
+}
+
+/// The signed number of cycles from `other` to `self`.
+impl Sub<AIRAC> for AIRAC {
+    type Output = i32;
+
+    fn sub(self, other: AIRAC) -> i32 {
+        self.index() - other.index()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn airac(ident: &str) -> AIRAC {
+        ident.parse().unwrap()
+    }
+
+    #[test]
+    fn test_add_sub() {
+        assert_eq!(airac("2205") + 1, airac("2206"));
+        assert_eq!(airac("2205") + 9, airac("2301"));
+        assert_eq!(airac("2205") + -5, airac("2113"));
+        assert_eq!(airac("2205") - 5, airac("2113"));
+        assert_eq!(airac("2205") + 0, airac("2205"));
+        assert_eq!(airac("2205") + 1, airac("2205").next());
+        assert_eq!(airac("2205") - 1, airac("2205").previous());
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut cycle = airac("2205");
+        cycle += 13;
+        assert_eq!(cycle, airac("2305"));
+        cycle -= 14;
+        assert_eq!(cycle, airac("2204"));
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(airac("2213") - airac("2201"), 12);
+        assert_eq!(airac("2001") - airac("1901"), 13);
+        assert_eq!(airac("1901") - airac("2001"), -13);
+        let a = airac("1805");
+        let b = airac("2511");
+        assert_eq!(a + (b - a), b);
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(airac("2205").checked_add(1), Some(airac("2206")));
+        assert_eq!(airac("2205").checked_sub(1), Some(airac("2204")));
+        assert_eq!(airac("2205").checked_add(i32::MAX), None);
+        assert_eq!(airac("2205").checked_sub(i32::MIN), None);
+        assert_eq!(airac("2205").checked_sub(10_000_000), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow() {
+        let _ = airac("2205") + 10_000_000;
+    }
+}
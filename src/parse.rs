@@ -0,0 +1,199 @@
+//! Lenient extraction of cycle references from free text, such as NOTAMs and
+//! email bodies, enabled with the `parse` feature. For strict parsing of a
+//! single identifier, use [`AIRAC::from_ident`] or [`str::parse`].
+
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use chrono::NaiveDate;
+use regex::{Captures, Regex};
+
+use crate::{CenturyPivot, AIRAC};
+
+static REFERENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?xi)
+        \bAIRAC\s+(?:AIP\s+)?AMDT\s+(?P<amdt_number>\d{2})/(?P<amdt_year>\d{2})\b
+        | \b(?:AIRAC\s+(?:cycle\s+)?|cycle\s+)
+          (?: (?P<ident>\d{4}) | (?P<slash_year>\d{2})/(?P<slash_number>\d{2}) )\b
+        | \b(?:eff(?:ective)?\.?|wef)\s+
+          (?P<eff_day>\d{1,2})\s+(?P<eff_month>[a-z]{3})[a-z]*\s+(?P<eff_year>\d{4}|\d{2})\b
+        | \b(?P<iso>\d{4}-\d{2}-\d{2})\b
+        ",
+    )
+    .unwrap()
+});
+
+/// The form a cycle reference was written in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Form {
+    /// A `YYNN` identifier, e.g. `AIRAC 2205` or `cycle 2205`.
+    Ident,
+    /// A `YY/NN` identifier, e.g. `cycle 22/05`.
+    Slashed,
+    /// An amendment number, e.g. `AIRAC AMDT 05/22` or `AIRAC AIP AMDT 05/22`.
+    Amendment,
+    /// An effective date, e.g. `eff 19 MAY 22` or `WEF 19 MAY 2022`.
+    EffectiveDate,
+    /// An ISO 8601 date, e.g. `2022-05-19`, referring to the cycle in effect
+    /// on that date.
+    IsoDate,
+}
+
+/// A cycle reference found in some text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Extracted {
+    /// The cycle referred to.
+    pub airac: AIRAC,
+    /// The byte range of the reference in the text.
+    pub span: Range<usize>,
+    /// The form the reference was written in.
+    pub form: Form,
+}
+
+/// Returns every cycle reference in the text, in order. Two digit years are
+/// interpreted with the default [`CenturyPivot`].
+///
+/// ```
+/// # use airac::parse::{extract_all, Form};
+/// let text = "Changes effective AIRAC 2205, published in AIRAC AMDT 04/22.";
+/// let found = extract_all(text);
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[0].airac.to_string(), "2205");
+/// assert_eq!(&text[found[0].span.clone()], "AIRAC 2205");
+/// assert_eq!(found[1].form, Form::Amendment);
+/// ```
+pub fn extract_all(text: &str) -> Vec<Extracted> {
+    extract_all_with(text, CenturyPivot::default())
+}
+
+/// Returns every cycle reference in the text, in order, choosing the century
+/// of two digit years with the given [`CenturyPivot`].
+pub fn extract_all_with(text: &str, pivot: CenturyPivot) -> Vec<Extracted> {
+    REFERENCE
+        .captures_iter(text)
+        .filter_map(|caps| {
+            let (airac, form) = resolve(&caps, pivot)?;
+            Some(Extracted {
+                airac,
+                span: caps.get(0)?.range(),
+                form,
+            })
+        })
+        .collect()
+}
+
+fn resolve(caps: &Captures, pivot: CenturyPivot) -> Option<(AIRAC, Form)> {
+    let int = |name| caps.name(name)?.as_str().parse::<u32>().ok();
+    if let Some(number) = int("amdt_number") {
+        let year = pivot.resolve(int("amdt_year")?);
+        let airac = AIRAC::from_year_and_number(year, number).ok()?;
+        Some((airac, Form::Amendment))
+    } else if let Some(ident) = caps.name("ident") {
+        let airac = AIRAC::from_ident_with(ident.as_str(), pivot).ok()?;
+        Some((airac, Form::Ident))
+    } else if let Some(number) = int("slash_number") {
+        let year = pivot.resolve(int("slash_year")?);
+        let airac = AIRAC::from_year_and_number(year, number).ok()?;
+        Some((airac, Form::Slashed))
+    } else if let Some(day) = int("eff_day") {
+        let month = caps.name("eff_month")?.as_str().to_ascii_lowercase();
+        let month = [
+            "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+        ]
+        .iter()
+        .position(|m| *m == month)? as u32
+            + 1;
+        let year = caps.name("eff_year")?.as_str();
+        let year = match year.len() {
+            2 => pivot.resolve(year.parse().ok()?),
+            _ => year.parse().ok()?,
+        };
+        let airac = AIRAC::try_from_ymd(year, month, day).ok()?;
+        Some((airac, Form::EffectiveDate))
+    } else {
+        let date = NaiveDate::parse_from_str(caps.name("iso")?.as_str(), "%Y-%m-%d").ok()?;
+        Some((AIRAC::try_from_date(date).ok()?, Form::IsoDate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(text: &str) -> Vec<(String, &str, Form)> {
+        extract_all(text)
+            .into_iter()
+            .map(|e| (e.airac.to_string(), &text[e.span], e.form))
+            .collect()
+    }
+
+    #[test]
+    fn test_forms() {
+        assert_eq!(
+            extract("AIRAC 2205"),
+            [("2205".into(), "AIRAC 2205", Form::Ident)]
+        );
+        assert_eq!(
+            extract("see cycle 22/05."),
+            [("2205".into(), "cycle 22/05", Form::Slashed)]
+        );
+        assert_eq!(
+            extract("AIRAC AMDT 05/22"),
+            [("2205".into(), "AIRAC AMDT 05/22", Form::Amendment)]
+        );
+        assert_eq!(
+            extract("EFF 19 MAY 22"),
+            [("2205".into(), "EFF 19 MAY 22", Form::EffectiveDate)]
+        );
+        assert_eq!(
+            extract("effective 19 May 2022"),
+            [("2205".into(), "effective 19 May 2022", Form::EffectiveDate)]
+        );
+        assert_eq!(
+            extract("from 2022-05-19"),
+            [("2205".into(), "2022-05-19", Form::IsoDate)]
+        );
+    }
+
+    #[test]
+    fn test_notam() {
+        let notam = "A1234/22 NOTAMN\n\
+            E) TRIGGER NOTAM - PERM AIRAC AIP AMDT 05/22 WEF 19 MAY 2022.\n\
+            CHANGES TO EGLL AD 2 PUBLISHED IN AIRAC cycle 2205, EFF 19 MAY 22.\n\
+            PREVIOUSLY 2022-04-21 (AIRAC 2204).";
+        let found = extract(notam);
+        assert_eq!(
+            found,
+            [
+                ("2205".into(), "AIRAC AIP AMDT 05/22", Form::Amendment),
+                ("2205".into(), "WEF 19 MAY 2022", Form::EffectiveDate),
+                ("2205".into(), "AIRAC cycle 2205", Form::Ident),
+                ("2205".into(), "EFF 19 MAY 22", Form::EffectiveDate),
+                ("2204".into(), "2022-04-21", Form::IsoDate),
+                ("2204".into(), "AIRAC 2204", Form::Ident),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spans_are_bytes() {
+        let text = "Änderung – AIRAC 2205";
+        let found = extract_all(text);
+        assert_eq!(&text[found[0].span.clone()], "AIRAC 2205");
+        assert_eq!(found[0].span.start, 14);
+    }
+
+    #[test]
+    fn test_invalid_references_are_skipped() {
+        assert!(extract("AIRAC 2214 and cycle 22/00 and 2022-02-30").is_empty());
+        assert!(extract("AIRAC 22051 and xAIRAC 2205 and eff 19 FOO 22").is_empty());
+    }
+
+    #[test]
+    fn test_pivot() {
+        let pivot = CenturyPivot::Sliding(AIRAC::from_ymd(2099, 12, 1));
+        let found = extract_all_with("AIRAC 0001", pivot);
+        assert_eq!(found[0].airac.year(), 2100);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::AIRAC;
+
+/// How the century of a two digit year in a `YYNN` identifier is chosen.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CenturyPivot {
+    /// Two digit years resolve into the hundred years starting with this
+    /// year, e.g. `Fixed(1969)` maps `69` to 1969 and `68` to 2068.
+    Fixed(i32),
+    /// Two digit years resolve into the hundred years centred on the year of
+    /// this cycle, e.g. relative to 2205, `72` is 1972 and `71` is 2071.
+    Sliding(AIRAC),
+}
+
+impl CenturyPivot {
+    /// Returns the four digit year for the last two digits of a year.
+    ///
+    /// A year beyond the range of `i32` saturates, and like any year outside
+    /// the range of dates is then rejected when constructing a cycle.
+    pub fn resolve(&self, yy: u32) -> i32 {
+        let start = match self {
+            Self::Fixed(start) => i64::from(*start),
+            Self::Sliding(reference) => i64::from(reference.year()) - 50,
+        };
+        let year = start + (i64::from(yy) - start).rem_euclid(100);
+        year.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+    }
+}
+
+impl Default for CenturyPivot {
+    /// The POSIX `strptime` pivot: `69` to `99` are in the 1900s, `00` to
+    /// `68` in the 2000s.
+    fn default() -> Self {
+        Self::Fixed(1969)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AiracError;
+
+    #[test]
+    fn test_default() {
+        let pivot = CenturyPivot::default();
+        assert_eq!(pivot.resolve(69), 1969);
+        assert_eq!(pivot.resolve(99), 1999);
+        assert_eq!(pivot.resolve(0), 2000);
+        assert_eq!(pivot.resolve(68), 2068);
+    }
+
+    #[test]
+    fn test_fixed() {
+        let pivot = CenturyPivot::Fixed(2050);
+        assert_eq!(pivot.resolve(50), 2050);
+        assert_eq!(pivot.resolve(99), 2099);
+        assert_eq!(pivot.resolve(0), 2100);
+        assert_eq!(pivot.resolve(49), 2149);
+    }
+
+    #[test]
+    fn test_sliding() {
+        let pivot = CenturyPivot::Sliding("2205".parse().unwrap());
+        assert_eq!(pivot.resolve(72), 1972);
+        assert_eq!(pivot.resolve(71), 2071);
+        let pivot = CenturyPivot::Sliding(AIRAC::from_ymd(2099, 12, 1));
+        assert_eq!(pivot.resolve(99), 2099);
+        assert_eq!(pivot.resolve(0), 2100);
+        assert_eq!(pivot.resolve(49), 2049);
+    }
+
+    #[test]
+    fn test_ident_across_centuries() {
+        let pivot = CenturyPivot::Sliding(AIRAC::from_ymd(2000, 1, 1));
+        let last = AIRAC::from_ident_with("9913", pivot).unwrap();
+        let first = AIRAC::from_ident_with("0001", pivot).unwrap();
+        assert_eq!(last.year(), 1999);
+        assert_eq!(first.year(), 2000);
+        assert_eq!(last.next(), first);
+
+        let pivot = CenturyPivot::Sliding(AIRAC::from_ymd(2100, 1, 1));
+        let last = AIRAC::from_ident_with("9913", pivot).unwrap();
+        let first = AIRAC::from_ident_with("0001", pivot).unwrap();
+        assert_eq!(last.year(), 2099);
+        assert_eq!(first.year(), 2100);
+        assert_eq!(last.next(), first);
+        assert_eq!(last.to_string(), "9913");
+        assert_eq!(last.full_ident(), "209913");
+        assert_eq!(first.full_ident(), "210001");
+
+        // 1998 has 14 cycles but 2098 does not.
+        assert!(AIRAC::from_ident_with("9814", CenturyPivot::Fixed(1950)).is_ok());
+        assert!(AIRAC::from_ident_with("9814", CenturyPivot::Fixed(2050)).is_err());
+    }
+
+    #[test]
+    fn test_extreme_pivots() {
+        assert_eq!(CenturyPivot::Fixed(i32::MAX).resolve(0), i32::MAX);
+        assert_eq!(CenturyPivot::Fixed(i32::MIN).resolve(56), i32::MIN + 4);
+        for pivot in [i32::MIN, i32::MAX] {
+            assert!(matches!(
+                AIRAC::from_ident_with("2205", CenturyPivot::Fixed(pivot)),
+                Err(AiracError::InvalidDate { .. })
+            ));
+        }
+    }
+}
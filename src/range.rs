@@ -0,0 +1,174 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
+
+use crate::schedule::Schedule;
+use crate::AIRAC;
+
+/// An iterator over consecutive cycles of a [`Schedule`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleRange<C> {
+    // Indices of the first cycle and one past the last cycle remaining.
+    front: i32,
+    back: i32,
+    schedule: PhantomData<C>,
+}
+
+/// An iterator over consecutive AIRAC cycles.
+pub type AiracRange = CycleRange<AIRAC>;
+
+impl<C: Schedule> CycleRange<C> {
+    /// The cycles from `start` up to, but not including, `end`.
+    pub fn new(start: C, end: C) -> Self {
+        Self {
+            front: start.index(),
+            back: end.index().max(start.index()),
+            schedule: PhantomData,
+        }
+    }
+
+    /// The cycles from `start` up to and including `end`.
+    pub fn inclusive(start: C, end: C) -> Self {
+        Self {
+            front: start.index(),
+            back: (end.index() + 1).max(start.index()),
+            schedule: PhantomData,
+        }
+    }
+
+    /// Returns true if the cycle is yet to be yielded by this range.
+    pub fn contains(&self, cycle: &C) -> bool {
+        (self.front..self.back).contains(&cycle.index())
+    }
+}
+
+impl<C: Schedule> From<Range<C>> for CycleRange<C> {
+    fn from(range: Range<C>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<C: Schedule> From<RangeInclusive<C>> for CycleRange<C> {
+    fn from(range: RangeInclusive<C>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::inclusive(start, end)
+    }
+}
+
+impl<C: Schedule> Iterator for CycleRange<C> {
+    type Item = C;
+
+    fn next(&mut self) -> Option<C> {
+        if self.front == self.back {
+            return None;
+        }
+        let cycle = cycle_at(self.front);
+        self.front += 1;
+        Some(cycle)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<C> {
+        let n = i32::try_from(n).unwrap_or(i32::MAX);
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    fn last(mut self) -> Option<C> {
+        self.next_back()
+    }
+}
+
+impl<C: Schedule> DoubleEndedIterator for CycleRange<C> {
+    fn next_back(&mut self) -> Option<C> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(cycle_at(self.back))
+    }
+}
+
+impl<C: Schedule> ExactSizeIterator for CycleRange<C> {}
+
+impl<C: Schedule> FusedIterator for CycleRange<C> {}
+
+// Ranges are built from cycles, so every index within one is supported.
+fn cycle_at<C: Schedule>(index: i32) -> C {
+    C::try_from_index(index).expect("the cycle is within the supported range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_half_open() {
+        let range = AiracRange::new("1901".parse().unwrap(), "1905".parse().unwrap());
+        let idents: Vec<String> = range.map(|a| a.to_string()).collect();
+        assert_eq!(idents, ["1901", "1902", "1903", "1904"]);
+    }
+
+    #[test]
+    fn test_inclusive() {
+        let start: AIRAC = "1901".parse().unwrap();
+        let end: AIRAC = "2305".parse().unwrap();
+        let range = AiracRange::inclusive(start, end);
+        assert_eq!(range.len(), 13 + 14 + 13 + 13 + 5);
+        assert_eq!(range.clone().next(), Some(start));
+        assert_eq!(range.clone().next_back(), Some(end));
+        assert_eq!(AiracRange::from(start..=end), range);
+    }
+
+    #[test]
+    fn test_double_ended() {
+        let mut range: AiracRange = ("2201".parse().unwrap()..="2203".parse().unwrap()).into();
+        assert_eq!(range.next_back().unwrap().to_string(), "2203");
+        assert_eq!(range.next().unwrap().to_string(), "2201");
+        assert_eq!(range.len(), 1);
+        assert_eq!(range.next_back().unwrap().to_string(), "2202");
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        let start: AIRAC = "2205".parse().unwrap();
+        assert_eq!(AiracRange::new(start, start).len(), 0);
+        assert_eq!(AiracRange::new(start, start.previous()).len(), 0);
+        assert_eq!(AiracRange::inclusive(start, start).len(), 1);
+    }
+
+    #[test]
+    fn test_nth() {
+        let mut range = AIRAC::cycles_in_year(2022).unwrap();
+        assert_eq!(range.nth(4).unwrap().to_string(), "2205");
+        assert_eq!(range.nth(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let start: AIRAC = "2205".parse().unwrap();
+        let next: Vec<String> = start.iter_from().take(3).map(|a| a.to_string()).collect();
+        assert_eq!(next, ["2205", "2206", "2207"]);
+        assert_eq!(start.iter_from().next_back().unwrap().try_next().ok(), None);
+    }
+
+    #[test]
+    fn test_cycles_in_year() {
+        let cycles: Vec<AIRAC> = AIRAC::cycles_in_year(2020).unwrap().collect();
+        assert_eq!(cycles.len(), 14);
+        assert_eq!(cycles[0].to_string(), "2001");
+        assert_eq!(cycles[13].to_string(), "2014");
+        assert_eq!(AIRAC::cycles_in_year(2021).unwrap().len(), 13);
+        for year in 1990..2100 {
+            let range = AIRAC::cycles_in_year(year).unwrap();
+            assert!(range.len() == 13 || range.len() == 14);
+            assert!(range.clone().all(|a| a.year() == year));
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Periodic schedules of fixed-length cycles, of which [`AIRAC`](crate::AIRAC) is one.
+//!
+//! A custom schedule is a type wrapping the start date of a cycle, with an
+//! anchor and a period. Every other method is provided, giving it the same
+//! API as [`AIRAC`](crate::AIRAC):
+//!
+//! ```
+//! use airac::schedule::Schedule;
+//! use airac::NaiveDate;
+//!
+//! /// A weekly cycle starting on Mondays.
+//! #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+//! struct Week(NaiveDate);
+//!
+//! impl Schedule for Week {
+//!     const ANCHOR: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+//!     const PERIOD_DAYS: i64 = 7;
+//!
+//!     fn from_start(starts: NaiveDate) -> Self {
+//!         Self(starts)
+//!     }
+//!
+//!     fn starts(&self) -> NaiveDate {
+//!         self.0
+//!     }
+//! }
+//!
+//! let week = Week::try_from_date(NaiveDate::from_ymd_opt(2024, 3, 6).unwrap()).unwrap();
+//! assert_eq!(week.ident(), "2410");
+//! assert_eq!(week.format("%{ident} from %d %b").to_string(), "2410 from 04 Mar");
+//! assert_eq!(Week::cycles_in_year(2024).unwrap().len(), 53);
+//! ```
+
+use std::fmt;
+use std::hash::Hash;
+
+use chrono::prelude::*;
+use chrono::Duration;
+
+use crate::milestones::Milestones;
+use crate::{AiracError, CenturyPivot, CycleFormat, CycleRange};
+
+/// A schedule of consecutive cycles of equal length.
+///
+/// Implementors wrap the start date of a cycle. Provided methods only ever
+/// call [`from_start`](Schedule::from_start) with a date a whole number of
+/// periods from the [anchor](Schedule::ANCHOR) whose cycle also has a
+/// representable end date.
+///
+/// # Identifier scheme
+///
+/// By default cycles are identified as `YYNN` and `YYYYNN`. A schedule with
+/// its own scheme overrides both identifiers and their parsers together:
+///
+/// - [`ident`](Schedule::ident) with
+///   [`from_ident_with`](Schedule::from_ident_with), which
+///   [`from_ident`](Schedule::from_ident) calls with the default pivot.
+/// - [`full_ident`](Schedule::full_ident) with
+///   [`from_full_ident`](Schedule::from_full_ident).
+///
+/// A scheme with a single, unambiguous form uses it for both.
+pub trait Schedule: Copy + Ord + Hash + fmt::Debug {
+    /// The start date of the cycle with index 0.
+    const ANCHOR: NaiveDate;
+
+    /// The length of every cycle in days, from 1 to 365.
+    const PERIOD_DAYS: i64;
+
+    /// The time of day, in UTC, at which cycles change.
+    const EFFECTIVE_TIME: NaiveTime = NaiveTime::MIN;
+
+    /// Wraps the start date of a cycle.
+    fn from_start(starts: NaiveDate) -> Self;
+
+    /// The date this cycle started on.
+    fn starts(&self) -> NaiveDate;
+
+    /// The year this cycle started in.
+    fn year(&self) -> i32 {
+        self.starts().year()
+    }
+
+    /// The number of this cycle within its year, starting from 1.
+    fn number(&self) -> u32 {
+        // Cycles are shorter than a year, so the first cycle of a year always
+        // starts within its first period of days.
+        self.starts().ordinal0() / Self::PERIOD_DAYS as u32 + 1
+    }
+
+    /// The identifier of this cycle, by default `YYNN`.
+    fn ident(&self) -> String {
+        format!("{:02}{:02}", self.year().rem_euclid(100), self.number())
+    }
+
+    /// The identifier of this cycle with a four digit year, by default
+    /// `YYYYNN`.
+    fn full_ident(&self) -> String {
+        format!("{:04}{:02}", self.year(), self.number())
+    }
+
+    /// Parses an [identifier](Schedule::ident) with the default
+    /// [`CenturyPivot`]. Schedules override
+    /// [`from_ident_with`](Schedule::from_ident_with) rather than this.
+    fn from_ident(ident: &str) -> Result<Self, AiracError> {
+        Self::from_ident_with(ident, CenturyPivot::default())
+    }
+
+    /// Parses an [identifier](Schedule::ident), by default `YYNN`, choosing
+    /// the century with the given [`CenturyPivot`].
+    fn from_ident_with(ident: &str, pivot: CenturyPivot) -> Result<Self, AiracError> {
+        let invalid = || AiracError::InvalidIdentifier(ident.to_string());
+        if ident.len() != 4 || !ident.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let yy: u32 = ident[..2].parse().map_err(|_| invalid())?;
+        let number: u32 = ident[2..].parse().map_err(|_| invalid())?;
+        Self::from_year_and_number(pivot.resolve(yy), number)
+    }
+
+    /// Parses a [full identifier](Schedule::full_ident), by default either
+    /// `YYYYNN` or `YYYY-NN`.
+    fn from_full_ident(ident: &str) -> Result<Self, AiracError> {
+        let invalid = || AiracError::InvalidIdentifier(ident.to_string());
+        // Checked first so that slicing by byte below cannot split a char.
+        if !ident.is_ascii() {
+            return Err(invalid());
+        }
+        let (year, number) = match ident.len() {
+            6 => (&ident[..4], &ident[4..]),
+            7 if ident.as_bytes()[4] == b'-' => (&ident[..4], &ident[5..]),
+            _ => return Err(invalid()),
+        };
+        if !year
+            .bytes()
+            .chain(number.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let year = year.parse().map_err(|_| invalid())?;
+        let number = number.parse().map_err(|_| invalid())?;
+        Self::from_year_and_number(year, number)
+    }
+
+    /// Returns the cycle with the given number within a year.
+    fn from_year_and_number(year: i32, number: u32) -> Result<Self, AiracError> {
+        let invalid = AiracError::InvalidCycleNumber { year, number };
+        if number == 0 {
+            return Err(invalid);
+        }
+        let first = NaiveDate::from_ymd_opt(year, 1, 1).ok_or(AiracError::InvalidDate {
+            year,
+            month: 1,
+            day: 1,
+        })?;
+        let first = Self::try_from_date(first)?;
+        let first = if first.year() == year {
+            first.index()
+        } else {
+            first.index() + 1
+        };
+        let cycle = i32::try_from(number - 1)
+            .ok()
+            .and_then(|n| first.checked_add(n))
+            .ok_or(AiracError::Overflow)
+            .and_then(Self::try_from_index)?;
+        if cycle.year() != year {
+            return Err(invalid);
+        }
+        Ok(cycle)
+    }
+
+    /// Returns the cycle valid on the date given, or an error if the cycle
+    /// cannot be represented.
+    fn try_from_date(target: NaiveDate) -> Result<Self, AiracError> {
+        let index = (target - Self::ANCHOR)
+            .num_days()
+            .div_euclid(Self::PERIOD_DAYS);
+        i32::try_from(index)
+            .ok()
+            .and_then(|index| Self::try_from_index(index).ok())
+            .ok_or(AiracError::OutOfRange(target))
+    }
+
+    /// Returns the cycle in effect at the given instant, converted to UTC.
+    /// Cycles change at [`EFFECTIVE_TIME`](Schedule::EFFECTIVE_TIME), so
+    /// before then on a start date the previous cycle is in effect.
+    fn try_at<Tz: TimeZone>(instant: DateTime<Tz>) -> Result<Self, AiracError> {
+        let instant = instant.with_timezone(&Utc).naive_utc();
+        let date = instant
+            .checked_sub_signed(Self::EFFECTIVE_TIME - NaiveTime::MIN)
+            .ok_or(AiracError::OutOfRange(instant.date()))?
+            .date();
+        Self::try_from_date(date)
+    }
+
+    /// Returns the cycle with the given [index](Schedule::index), or
+    /// [`AiracError::Overflow`] if it is out of the supported range.
+    fn try_from_index(index: i32) -> Result<Self, AiracError> {
+        let period = Duration::days(Self::PERIOD_DAYS);
+        Self::ANCHOR
+            .checked_add_signed(period * index)
+            // The cycle must also have a representable end date.
+            .filter(|starts| starts.checked_add_signed(period).is_some())
+            .map(Self::from_start)
+            .ok_or(AiracError::Overflow)
+    }
+
+    /// The number of cycles since the one starting on the
+    /// [anchor](Schedule::ANCHOR), which has index 0.
+    fn index(&self) -> i32 {
+        ((self.starts() - Self::ANCHOR).num_days() / Self::PERIOD_DAYS) as i32
+    }
+
+    /// The date this cycle became ineffective, as the day began.
+    fn ends(&self) -> NaiveDate {
+        self.starts() + Duration::days(Self::PERIOD_DAYS)
+    }
+
+    /// The instant this cycle became effective, at
+    /// [`EFFECTIVE_TIME`](Schedule::EFFECTIVE_TIME) on its start date.
+    fn starts_at(&self) -> DateTime<Utc> {
+        self.starts().and_time(Self::EFFECTIVE_TIME).and_utc()
+    }
+
+    /// The instant this cycle became ineffective, at
+    /// [`EFFECTIVE_TIME`](Schedule::EFFECTIVE_TIME) on its end date.
+    fn ends_at(&self) -> DateTime<Utc> {
+        self.ends().and_time(Self::EFFECTIVE_TIME).and_utc()
+    }
+
+    /// Returns true if this cycle was in effect at the given instant.
+    fn contains_instant<Tz: TimeZone>(&self, instant: DateTime<Tz>) -> bool {
+        let instant = instant.with_timezone(&Utc);
+        self.starts_at() <= instant && instant < self.ends_at()
+    }
+
+    /// Returns the cycle `cycles` after this one, or `None` if it is out of
+    /// the supported range.
+    fn checked_add(self, cycles: i32) -> Option<Self> {
+        let index = self.index().checked_add(cycles)?;
+        Self::try_from_index(index).ok()
+    }
+
+    /// Returns the cycle `cycles` before this one, or `None` if it is out of
+    /// the supported range.
+    fn checked_sub(self, cycles: i32) -> Option<Self> {
+        let index = self.index().checked_sub(cycles)?;
+        Self::try_from_index(index).ok()
+    }
+
+    /// Returns the cycles that start in the given year.
+    fn cycles_in_year(year: i32) -> Result<CycleRange<Self>, AiracError> {
+        let first = Self::from_year_and_number(year, 1)?;
+        let next = Self::from_year_and_number(year.checked_add(1).ok_or(AiracError::Overflow)?, 1)?;
+        Ok(CycleRange::new(first, next))
+    }
+
+    /// Returns an iterator over this cycle and every cycle after it.
+    fn iter_from(&self) -> CycleRange<Self> {
+        let last = Self::try_from_date(NaiveDate::MAX - Duration::days(Self::PERIOD_DAYS))
+            .expect("the last cycle is within the supported range");
+        CycleRange::inclusive(*self, last)
+    }
+
+    /// Formats this cycle with a pattern, see [`AIRAC::format`](crate::AIRAC::format).
+    fn format<'a>(&self, pattern: &'a str) -> CycleFormat<'a, Self> {
+        CycleFormat::new(*self, pattern)
+    }
+
+    /// Returns the publication milestones leading up to this cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a milestone falls before the earliest representable date.
+    fn milestones(&self) -> Milestones {
+        Milestones::for_cycle(self)
+    }
+
+    /// Returns the publication milestones leading up to this cycle, or
+    /// [`AiracError::Overflow`] if one falls before the earliest
+    /// representable date.
+    fn try_milestones(&self) -> Result<Milestones, AiracError> {
+        Milestones::try_for_cycle(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::date;
+    use crate::AIRAC;
+
+    /// A schedule of 56 day cycles anchored on 2001.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+    struct Bimonthly(NaiveDate);
+
+    impl Schedule for Bimonthly {
+        const ANCHOR: NaiveDate = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+        const PERIOD_DAYS: i64 = 56;
+
+        fn from_start(starts: NaiveDate) -> Self {
+            Self(starts)
+        }
+
+        fn starts(&self) -> NaiveDate {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_airac_schedule() {
+        let airac: AIRAC = "2205".parse().unwrap();
+        assert_eq!(<AIRAC as Schedule>::ident(&airac), "2205");
+        assert_eq!(<AIRAC as Schedule>::index(&airac), airac.index());
+        assert_eq!(
+            <AIRAC as Schedule>::try_from_date(date(2022, 6, 1)),
+            Ok(airac)
+        );
+    }
+
+    #[test]
+    fn test_custom_schedule() {
+        let cycle = Bimonthly::try_from_date(date(2022, 6, 1)).unwrap();
+        assert_eq!(cycle.starts(), date(2022, 4, 21));
+        assert_eq!(cycle.ends(), date(2022, 6, 16));
+        assert_eq!(cycle.index(), 15);
+        assert_eq!(cycle.ident(), "2202");
+        assert_eq!(cycle.full_ident(), "202202");
+        assert_eq!(Bimonthly::from_ident("2202"), Ok(cycle));
+        assert_eq!(Bimonthly::from_full_ident("2022-02"), Ok(cycle));
+        assert_eq!(cycle.checked_sub(1).unwrap().ends(), cycle.starts());
+        assert!(matches!(
+            Bimonthly::from_ident("2207"),
+            Err(AiracError::InvalidCycleNumber { .. })
+        ));
+    }
+
+    #[test]
+    fn test_custom_schedule_api() {
+        let cycles: Vec<String> = Bimonthly::cycles_in_year(2022)
+            .unwrap()
+            .map(|c| c.ident())
+            .collect();
+        assert_eq!(cycles, ["2201", "2202", "2203", "2204", "2205", "2206"]);
+        let cycle = Bimonthly::from_ident("2202").unwrap();
+        assert_eq!(
+            cycle.format("%{ident} %d %b – %Ed %Eb").to_string(),
+            "2202 21 Apr – 16 Jun"
+        );
+        assert_eq!(
+            cycle.milestones(),
+            "2204".parse::<AIRAC>().unwrap().milestones()
+        );
+        assert_eq!(cycle.iter_from().nth(1), cycle.checked_add(1));
+    }
+}
@@ -0,0 +1,168 @@
+//! Serde support for [`AIRAC`], enabled with the `serde` feature.
+//!
+//! By default an [`AIRAC`] is serialized as its `YYNN` identifier, e.g.
+//! `"2205"`. The modules here can be used with `#[serde(with = "...")]` to
+//! choose a different representation for a field:
+//!
+//! ```
+//! # use airac::AIRAC;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Dataset {
+//!     cycle: AIRAC,
+//!     #[serde(with = "airac::serde::as_date")]
+//!     effective: AIRAC,
+//!     #[serde(with = "airac::serde::as_index")]
+//!     index: AIRAC,
+//!     #[serde(with = "airac::serde::as_full_ident")]
+//!     full: AIRAC,
+//! }
+//! ```
+
+use std::fmt;
+
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::AIRAC;
+
+impl Serialize for AIRAC {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AIRAC {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdentVisitor;
+
+        impl de::Visitor<'_> for IdentVisitor {
+            type Value = AIRAC;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an AIRAC identifier of the form YYNN")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<AIRAC, E> {
+                AIRAC::from_ident(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(IdentVisitor)
+    }
+}
+
+/// Serialize an [`AIRAC`] as the ISO 8601 date it became effective, e.g.
+/// `"2022-05-19"`.
+///
+/// When deserializing, any date is accepted and resolves to the cycle in
+/// effect on that date.
+pub mod as_date {
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+    use chrono::NaiveDate;
+
+    use crate::AIRAC;
+
+    pub fn serialize<S: Serializer>(airac: &AIRAC, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&airac.starts().format("%Y-%m-%d"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AIRAC, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let date = NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(de::Error::custom)?;
+        AIRAC::try_from_date(date).map_err(de::Error::custom)
+    }
+}
+
+/// Serialize an [`AIRAC`] as its [index](AIRAC::index).
+pub mod as_index {
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::AIRAC;
+
+    pub fn serialize<S: Serializer>(airac: &AIRAC, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(airac.index())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AIRAC, D::Error> {
+        let index = i32::deserialize(deserializer)?;
+        AIRAC::try_from_index(index).map_err(de::Error::custom)
+    }
+}
+
+/// Serialize an [`AIRAC`] as its [full identifier](AIRAC::full_ident), e.g.
+/// `"202205"`.
+pub mod as_full_ident {
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::AIRAC;
+
+    pub fn serialize<S: Serializer>(airac: &AIRAC, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&airac.full_ident())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AIRAC, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        AIRAC::from_full_ident(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::AIRAC;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Dataset {
+        cycle: AIRAC,
+        #[serde(with = "super::as_date")]
+        effective: AIRAC,
+        #[serde(with = "super::as_index")]
+        index: AIRAC,
+        #[serde(with = "super::as_full_ident")]
+        full: AIRAC,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let airac = AIRAC::from_ymd(2022, 5, 23);
+        let dataset = Dataset {
+            cycle: airac,
+            effective: airac,
+            index: airac,
+            full: airac,
+        };
+        let json = serde_json::to_string(&dataset).unwrap();
+        assert_eq!(
+            json,
+            r#"{"cycle":"2205","effective":"2022-05-19","index":31,"full":"202205"}"#
+        );
+        assert_eq!(serde_json::from_str::<Dataset>(&json).unwrap(), dataset);
+    }
+
+    #[test]
+    fn test_default_representation() {
+        let airac: AIRAC = serde_json::from_str(r#""2014""#).unwrap();
+        assert_eq!(airac, AIRAC::from_ymd(2020, 12, 31));
+        assert!(serde_json::from_str::<AIRAC>(r#""2214""#).is_err());
+        assert!(serde_json::from_str::<AIRAC>("2205").is_err());
+    }
+
+    #[test]
+    fn test_as_date_resolves_any_day() {
+        let json = r#"{"cycle":"2205","effective":"2022-05-23","index":31,"full":"202205"}"#;
+        let dataset: Dataset = serde_json::from_str(json).unwrap();
+        assert_eq!(dataset.effective, dataset.cycle);
+    }
+
+    #[test]
+    fn test_as_full_ident_invalid() {
+        for full in ["2205", "20225", "202214", "2022O5", "123é5"] {
+            let json = format!(
+                r#"{{"cycle":"2205","effective":"2022-05-19","index":31,"full":"{}"}}"#,
+                full
+            );
+            assert!(serde_json::from_str::<Dataset>(&json).is_err(), "{}", full);
+        }
+    }
+}
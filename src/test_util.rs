@@ -0,0 +1,14 @@
+//! Fixtures shared by the unit tests.
+
+use chrono::NaiveDate;
+
+use crate::AIRAC;
+
+pub(crate) fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+/// AIRAC 2205, effective 2022-05-19.
+pub(crate) fn airac() -> AIRAC {
+    "2205".parse().unwrap()
+}
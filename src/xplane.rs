@@ -0,0 +1,168 @@
+//! X-Plane `cycle_info.txt` files, enabled with the `xplane` feature.
+//!
+//! ```text
+//! AIRAC cycle    : 2205
+//! Version        : 1
+//! Valid (from/to): 19/MAY/2022 - 16/JUN/2022
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+use crate::{AiracError, AIRAC};
+
+/// The contents of a `cycle_info.txt` file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CycleInfo {
+    /// The declared cycle.
+    pub airac: AIRAC,
+    /// The revision of the data for this cycle.
+    pub version: u32,
+    /// The declared date the data becomes valid.
+    pub valid_from: NaiveDate,
+    /// The declared date the data stops being valid.
+    pub valid_to: NaiveDate,
+}
+
+impl CycleInfo {
+    /// Returns correct cycle information for a cycle, at version 1.
+    pub fn for_airac(airac: AIRAC) -> Self {
+        Self {
+            airac,
+            version: 1,
+            valid_from: airac.starts(),
+            valid_to: airac.ends(),
+        }
+    }
+
+    /// Returns true if the declared validity matches the cycle's
+    /// [`starts`](AIRAC::starts) and [`ends`](AIRAC::ends) dates.
+    pub fn is_consistent(&self) -> bool {
+        self.valid_from == self.airac.starts() && self.valid_to == self.airac.ends()
+    }
+}
+
+impl FromStr for CycleInfo {
+    type Err = AiracError;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| AiracError::InvalidCycleInfo(reason.to_string());
+        let mut airac = None;
+        let mut version = None;
+        let mut validity = None;
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "AIRAC cycle" => airac = Some(AIRAC::from_ident(value)?),
+                "Version" => {
+                    version = Some(value.parse().map_err(|_| invalid("invalid version"))?);
+                }
+                "Valid (from/to)" => {
+                    let (from, to) = value
+                        .split_once('-')
+                        .ok_or_else(|| invalid("invalid validity"))?;
+                    let date = |s: &str| {
+                        NaiveDate::parse_from_str(s.trim(), "%d/%b/%Y")
+                            .map_err(|_| invalid("invalid validity date"))
+                    };
+                    validity = Some((date(from)?, date(to)?));
+                }
+                _ => (),
+            }
+        }
+        let (valid_from, valid_to) = validity.ok_or_else(|| invalid("missing validity"))?;
+        Ok(Self {
+            airac: airac.ok_or_else(|| invalid("missing AIRAC cycle"))?,
+            version: version.ok_or_else(|| invalid("missing version"))?,
+            valid_from,
+            valid_to,
+        })
+    }
+}
+
+/// Writes the contents of a `cycle_info.txt` file.
+impl fmt::Display for CycleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = |date: NaiveDate| date.format("%d/%b/%Y").to_string().to_uppercase();
+        writeln!(f, "AIRAC cycle    : {}", self.airac)?;
+        writeln!(f, "Version        : {}", self.version)?;
+        writeln!(
+            f,
+            "Valid (from/to): {} - {}",
+            date(self.valid_from),
+            date(self.valid_to)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CYCLE_INFO: &str = "AIRAC cycle    : 2205\n\
+        Version        : 1\n\
+        Valid (from/to): 19/MAY/2022 - 16/JUN/2022\n";
+
+    #[test]
+    fn test_parse() {
+        let info: CycleInfo = CYCLE_INFO.parse().unwrap();
+        assert_eq!(info.airac.to_string(), "2205");
+        assert_eq!(info.version, 1);
+        assert_eq!(
+            info.valid_from,
+            NaiveDate::from_ymd_opt(2022, 5, 19).unwrap()
+        );
+        assert_eq!(info.valid_to, NaiveDate::from_ymd_opt(2022, 6, 16).unwrap());
+        assert!(info.is_consistent());
+    }
+
+    #[test]
+    fn test_parse_with_extra_lines() {
+        let contents = format!(
+            "{}\r\nForum support  : https://example.com\r\n",
+            CYCLE_INFO.replace('\n', "\r\n")
+        );
+        let info: CycleInfo = contents.parse().unwrap();
+        assert_eq!(info, CycleInfo::for_airac("2205".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        let info: CycleInfo = CYCLE_INFO
+            .replace("16/JUN/2022", "15/JUN/2022")
+            .parse()
+            .unwrap();
+        assert!(!info.is_consistent());
+        let info: CycleInfo = CYCLE_INFO.replace("2205", "2206").parse().unwrap();
+        assert!(!info.is_consistent());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!("".parse::<CycleInfo>().is_err());
+        assert!(CYCLE_INFO
+            .replace("2205", "2214")
+            .parse::<CycleInfo>()
+            .is_err());
+        assert!(CYCLE_INFO
+            .replace("19/MAY", "19/FOO")
+            .parse::<CycleInfo>()
+            .is_err());
+        assert!(CYCLE_INFO
+            .replace(": 1", ": one")
+            .parse::<CycleInfo>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_write() {
+        let info = CycleInfo::for_airac("2205".parse().unwrap());
+        assert_eq!(info.to_string(), CYCLE_INFO);
+        assert_eq!(info.to_string().parse::<CycleInfo>().unwrap(), info);
+    }
+}
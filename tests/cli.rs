@@ -0,0 +1,122 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn airac(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_airac"))
+        .env("AIRAC_NOW", "2022-05-23T12:00:00Z")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = airac(args);
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_at() {
+    assert_eq!(
+        stdout(&["at", "2022-05-23"]),
+        "2205 2022-05-19 2022-06-16\n"
+    );
+}
+
+#[test]
+fn test_info_json() {
+    assert_eq!(
+        stdout(&["info", "2205", "--json"]),
+        r#"{"ends":"2022-06-16","full_ident":"202205","ident":"2205","index":31,"number":5,"starts":"2022-05-19","year":2022}"#
+            .to_owned()
+            + "\n"
+    );
+}
+
+#[test]
+fn test_between() {
+    let out = stdout(&["between", "2201", "2022-13"]);
+    assert_eq!(out.lines().count(), 13);
+    assert!(out.starts_with("2201 "));
+    assert!(out.ends_with("2213 2022-12-29 2023-01-26\n"));
+}
+
+#[test]
+fn test_current() {
+    assert_eq!(stdout(&["current"]), "2205 2022-05-19 2022-06-16\n");
+}
+
+#[test]
+fn test_next() {
+    let out = stdout(&["next", "-n", "6"]);
+    assert_eq!(out.lines().count(), 6);
+    assert!(out.starts_with("2206 "));
+    assert!(out.ends_with("2211 2022-11-03 2022-12-01\n"));
+    let json = stdout(&["--json", "next", "-n", "2"]);
+    assert!(json.starts_with('['));
+}
+
+#[test]
+fn test_json_shape() {
+    let json = stdout(&["next", "-n", "1", "--json"]);
+    assert!(json.starts_with("[{") && json.ends_with("}]\n"), "{}", json);
+    assert_eq!(stdout(&["next", "-n", "0", "--json"]), "[]\n");
+    assert!(stdout(&["between", "2205", "2205", "--json"]).starts_with("[{"));
+    assert!(stdout(&["current", "--json"]).starts_with('{'));
+    assert!(stdout(&["at", "2022-05-23", "--json"]).starts_with('{'));
+}
+
+#[test]
+fn test_previous() {
+    assert_eq!(
+        stdout(&["previous", "-n", "2"]),
+        "2204 2022-04-21 2022-05-19\n2203 2022-03-24 2022-04-21\n"
+    );
+}
+
+#[test]
+fn test_invalid_now() {
+    let output = Command::new(env!("CARGO_BIN_EXE_airac"))
+        .env("AIRAC_NOW", "yesterday")
+        .arg("current")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_invalid_input() {
+    let output = airac(&["info", "2214"]);
+    assert_eq!(output.status.code(), Some(1));
+    let output = airac(&["info", "123é5"]);
+    assert_eq!(output.status.code(), Some(1));
+    let output = airac(&["at", "2022-02-30"]);
+    assert_eq!(output.status.code(), Some(1));
+    let output = airac(&["between", "2201"]);
+    assert_eq!(output.status.code(), Some(2));
+    let output = airac(&["ics", "--json"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_oversized_count() {
+    for command in ["next", "previous"] {
+        let output = airac(&[command, "-n", "18446744073709551615"]);
+        assert_eq!(output.status.code(), Some(1), "{:?}", output);
+        assert!(output.stdout.is_empty());
+    }
+}
+
+#[test]
+fn test_ics() {
+    let ics = stdout(&["ics", "--from", "2301", "--count", "26"]);
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 26);
+    assert!(ics.contains("UID:202301@airac\r\n"));
+    assert!(ics.contains("UID:202413@airac\r\n"));
+    assert!(ics.contains("DTSTAMP:20220523T120000Z\r\n"));
+    let ics = stdout(&["ics", "--count", "1", "--milestones"]);
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 4);
+    assert!(ics.contains("SUMMARY:AIRAC 2205 receipt\r\n"));
+}